@@ -3,27 +3,61 @@ use crate::operand::Item;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
+use std::mem::size_of;
 
 #[derive(Debug)]
 pub struct ArrayMap {
     pub id: usize,
     pub map: HashMap<usize, Array>,
+    // array_id => class name of the array. e.g. [I, [Ljava/lang/String;
+    pub class_name_map: HashMap<usize, usize>,
+    // array_id => the estimated bytes of the array
+    pub size_map: HashMap<usize, usize>,
+    // the estimated bytes of the arrays
+    pub size: usize,
 }
 impl ArrayMap {
     pub fn new() -> ArrayMap {
         ArrayMap {
             id: 0,
             map: HashMap::new(),
+            class_name_map: HashMap::new(),
+            size_map: HashMap::new(),
+            size: 0,
         }
     }
 
-    pub fn add(&mut self, value: Array) -> usize {
+    // the ids are not reused after the arrays are collected.
+    // the size is computed by get_array_size because the primitive types are not kept
+    pub fn add(&mut self, value: Array, class_name_id: usize, size: usize) -> usize {
         let id = self.id;
         self.id += 1;
+        self.size += size;
         self.map.insert(id, value);
+        self.class_name_map.insert(id, class_name_id);
+        self.size_map.insert(id, size);
         id
     }
 
+    // the arrays which are not live are removed
+    pub fn retain<F>(&mut self, is_live: F)
+    where
+        F: Fn(&usize) -> bool,
+    {
+        self.map.retain(|id, _| is_live(id));
+        self.class_name_map.retain(|id, _| is_live(id));
+        self.size_map.retain(|id, _| is_live(id));
+        self.size = self.size_map.values().sum();
+    }
+
+    pub fn get_size(&self, id: &usize) -> Option<usize> {
+        self.size_map.get(id).cloned()
+    }
+
+    pub fn get_class_name(&self, id: &usize) -> Option<usize> {
+        self.class_name_map.get(id).cloned()
+    }
+
     pub fn get(&self, id: &usize) -> Option<&Array> {
         self.map.get(id)
     }
@@ -45,11 +79,45 @@ pub enum PrimitiveArrayType {
     TLong = 11,
 }
 
+// the elements of Array::Custom are Null, Objectref, Arrayref or String.
+// the elements of Array::Array are Null or Arrayref
 #[derive(Clone, Debug)]
 pub enum Array {
     Primitive(RefCell<Vec<(Item, Item)>>),
-    Array(RefCell<Vec<usize>>),
-    Custom(RefCell<Vec<usize>>),
+    Array(RefCell<Vec<Item>>),
+    Custom(RefCell<Vec<Item>>),
+}
+
+pub const ARRAY_ELEMENT_SIZE: usize = size_of::<Item>();
+pub const CUSTOM_ELEMENT_SIZE: usize = size_of::<Item>();
+
+// the primitive elements are charged the same bytes as Java. e.g. 4 bytes for int
+pub fn get_primitive_element_size(type_index: usize) -> usize {
+    match type_index {
+        // TBoolean, TByte
+        4 | 8 => 1,
+        // TChar, TShort
+        5 | 9 => 2,
+        // TFloat, TInt
+        6 | 10 => 4,
+        // TDouble, TLong
+        7 | 11 => 8,
+        _ => unreachable!("should be primitive array type. actual: {}", type_index),
+    }
+}
+
+pub fn get_array_size(length: usize, element_size: usize) -> usize {
+    size_of::<Array>().saturating_add(length.saturating_mul(element_size))
+}
+
+impl Array {
+    pub fn len(&self) -> usize {
+        match self {
+            Array::Primitive(items) => items.borrow().len(),
+            Array::Array(items) => items.borrow().len(),
+            Array::Custom(items) => items.borrow().len(),
+        }
+    }
 }
 
 impl fmt::Display for Array {
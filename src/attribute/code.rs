@@ -48,8 +48,13 @@ impl Code {
             index = update_index;
         }
 
-        let (exception_table_length, index) = extract_x_byte_as_usize(inputs, index, 2);
-        let exception_table = Vec::with_capacity(exception_table_length);
+        let (exception_table_length, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut exception_table = Vec::with_capacity(exception_table_length);
+        for _ in 0..exception_table_length {
+            let (item, update_index) = ExceptionTableItem::new(inputs, index);
+            index = update_index;
+            exception_table.push(item);
+        }
 
         let (attributes_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
         let mut attribute_info = Vec::with_capacity(attributes_count);
@@ -87,6 +92,10 @@ impl fmt::Display for Code {
             }
             code_strs.push(format!("{}: {}", index, code));
         }
+        let mut exception_strs = Vec::with_capacity(self.exception_table_length);
+        for item in self.exception_table.iter() {
+            exception_strs.push(format!("{}", item));
+        }
         let mut attribute_strs = Vec::with_capacity(self.attributes_count);
         for item in self.attribute_info.iter() {
             attribute_strs.push(format!("{}", item));
@@ -97,10 +106,14 @@ impl fmt::Display for Code {
             "Code:
   stack:{}, locals={}, args_size=?
     {}
+  Exception table:
+     from    to  target type
+    {}
   {}",
             self.max_stack,
             self.max_locals,
             code_strs.join("\n    "),
+            exception_strs.join("\n    "),
             attribute_strs.join("\n  "),
         )
     }
@@ -111,5 +124,42 @@ pub struct ExceptionTableItem {
     pub start_pc: u16,   //u2
     pub end_pc: u16,     //u2
     pub handler_pc: u16, //u2
-    pub catch_type: u16, //u2
+    pub catch_type: u16, //u2 0 means any. it is used for finally
+}
+
+impl ExceptionTableItem {
+    pub fn new(inputs: &[u8], index: usize) -> (ExceptionTableItem, usize) {
+        let (start_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (end_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (handler_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (catch_type, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ExceptionTableItem {
+                start_pc: start_pc as u16,
+                end_pc: end_pc as u16,
+                handler_pc: handler_pc as u16,
+                catch_type: catch_type as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn is_in_range(&self, pc: usize) -> bool {
+        self.start_pc as usize <= pc && pc < self.end_pc as usize
+    }
+}
+
+impl fmt::Display for ExceptionTableItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let catch_type = if self.catch_type == 0 {
+            String::from("any")
+        } else {
+            format!("#{}", self.catch_type)
+        };
+        write!(
+            f,
+            "{:>5} {:>5} {:>5}   {}",
+            self.start_pc, self.end_pc, self.handler_pc, catch_type
+        )
+    }
 }
@@ -8,20 +8,20 @@ use std::fmt;
 #[derive(Debug)]
 pub enum Attribute {
     SourceFile(SourceFile),
-    InnerClasses,
-    EnclosingMethod,
+    InnerClasses(InnerClasses),
+    EnclosingMethod(EnclosingMethod),
     SourceDebugExtension,
-    BootstrapMethods,
-    ConstantValue,
+    BootstrapMethods(BootstrapMethods),
+    ConstantValue(ConstantValue),
     Code(Code),
-    Exceptions,
+    Exceptions(Exceptions),
     RuntimeVisibleParameterAnnotations,
     RuntimeInvisibleParameterAnnotations,
     AnnotationDefault,
     MethodParameters,
     Synthetic,
     Deprecated,
-    Signature,
+    Signature(Signature),
     RuntimeVisibleAnnotations,
     RuntimeInvisibleAnnotations,
     LineNumberTable(LineNumberTable),
@@ -30,6 +30,7 @@ pub enum Attribute {
     StackMapTable(StackMapTable),
     RuntimeVisibleTypeAnnotations,
     RuntimeInvisibleTypeAnnotations,
+    Unknown(UnknownAttribute),
 }
 
 impl Attribute {
@@ -66,7 +67,35 @@ impl Attribute {
                     );
                     (Attribute::Code(item), index)
                 }
-                _ => unimplemented!(),
+                AttributeTag::Exceptions => {
+                    let (item, index) = Exceptions::new(inputs, index, attribute_name_index);
+                    (Attribute::Exceptions(item), index)
+                }
+                AttributeTag::Signature => {
+                    let (item, index) = Signature::new(inputs, index, attribute_name_index);
+                    (Attribute::Signature(item), index)
+                }
+                AttributeTag::InnerClasses => {
+                    let (item, index) = InnerClasses::new(inputs, index, attribute_name_index);
+                    (Attribute::InnerClasses(item), index)
+                }
+                AttributeTag::BootstrapMethods => {
+                    let (item, index) = BootstrapMethods::new(inputs, index, attribute_name_index);
+                    (Attribute::BootstrapMethods(item), index)
+                }
+                AttributeTag::EnclosingMethod => {
+                    let (item, index) = EnclosingMethod::new(inputs, index, attribute_name_index);
+                    (Attribute::EnclosingMethod(item), index)
+                }
+                AttributeTag::ConstantValue => {
+                    let (item, index) = ConstantValue::new(inputs, index, attribute_name_index);
+                    (Attribute::ConstantValue(item), index)
+                }
+                // the attributes which are not used are skipped. e.g. NestHost and NestMembers
+                _ => {
+                    let (item, index) = UnknownAttribute::new(inputs, index, attribute_name_index);
+                    (Attribute::Unknown(item), index)
+                }
             }
         } else {
             panic!(
@@ -84,6 +113,13 @@ impl fmt::Display for Attribute {
             Attribute::Code(val) => write!(f, "{}", val),
             Attribute::LineNumberTable(val) => write!(f, "{}", val),
             Attribute::StackMapTable(val) => write!(f, "{}", val),
+            Attribute::Exceptions(val) => write!(f, "{}", val),
+            Attribute::Signature(val) => write!(f, "{}", val),
+            Attribute::InnerClasses(val) => write!(f, "{}", val),
+            Attribute::BootstrapMethods(val) => write!(f, "{}", val),
+            Attribute::ConstantValue(val) => write!(f, "{}", val),
+            Attribute::EnclosingMethod(val) => write!(f, "{}", val),
+            Attribute::Unknown(val) => write!(f, "{}", val),
             _ => unimplemented!(),
         }
     }
@@ -115,6 +151,8 @@ pub enum AttributeTag {
     StackMapTable,
     RuntimeVisibleTypeAnnotations,
     RuntimeInvisibleTypeAnnotations,
+    // the attributes which JVMS doesn't define or are added by the later versions
+    Unknown,
 }
 
 impl From<String> for AttributeTag {
@@ -147,11 +185,43 @@ impl From<String> for AttributeTag {
             "StackMapTable" => AttributeTag::StackMapTable,
             "RuntimeVisibleTypeAnnotations" => AttributeTag::RuntimeVisibleTypeAnnotations,
             "RuntimeInvisibleTypeAnnotations" => AttributeTag::RuntimeInvisibleTypeAnnotations,
-            _ => unreachable!(),
+            _ => AttributeTag::Unknown,
         }
     }
 }
 
+// only attribute_length is read to skip the info
+#[derive(Debug)]
+pub struct UnknownAttribute {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+}
+
+impl UnknownAttribute {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (UnknownAttribute, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let unknown_attribute = UnknownAttribute {
+            attribute_name_index,
+            attribute_length: attribute_length as u32,
+        };
+        (unknown_attribute, index + attribute_length)
+    }
+}
+
+impl fmt::Display for UnknownAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown: #{} length: {}",
+            self.attribute_name_index, self.attribute_length
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct SourceFile {
     pub attribute_name_index: u16, // u2
@@ -182,6 +252,318 @@ impl fmt::Display for SourceFile {
     }
 }
 
+#[derive(Debug)]
+pub struct Signature {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub signature_index: u16,      // u2
+}
+
+impl Signature {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Signature, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (signature_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let signature_index = signature_index as u16;
+
+        let signature = Signature {
+            attribute_name_index,
+            attribute_length,
+            signature_index,
+        };
+        (signature, index)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Signature: #{}", self.signature_index)
+    }
+}
+
+#[derive(Debug)]
+pub struct EnclosingMethod {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub class_index: usize,        // u2
+    pub method_index: usize,       // u2 0 means the class is not enclosed by a method
+}
+
+impl EnclosingMethod {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (EnclosingMethod, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (method_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        let enclosing_method = EnclosingMethod {
+            attribute_name_index,
+            attribute_length,
+            class_index,
+            method_index,
+        };
+        (enclosing_method, index)
+    }
+}
+
+impl fmt::Display for EnclosingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EnclosingMethod: #{}.#{}",
+            self.class_index, self.method_index
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ConstantValue {
+    pub attribute_name_index: u16,  // u2
+    pub attribute_length: u32,      // u4
+    pub constantvalue_index: usize, // u2
+}
+
+impl ConstantValue {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (ConstantValue, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (constantvalue_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        let constant_value = ConstantValue {
+            attribute_name_index,
+            attribute_length,
+            constantvalue_index,
+        };
+        (constant_value, index)
+    }
+}
+
+impl fmt::Display for ConstantValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ConstantValue: #{}", self.constantvalue_index)
+    }
+}
+
+#[derive(Debug)]
+pub struct InnerClasses {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub number_of_classes: usize,  // u2
+    pub classes: Vec<InnerClass>,
+}
+
+impl InnerClasses {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (InnerClasses, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_classes, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut classes = Vec::with_capacity(number_of_classes);
+        for _ in 0..number_of_classes {
+            let (inner_class_info_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (outer_class_info_index, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let (inner_name_index, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (inner_class_access_flags, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+
+            classes.push(InnerClass {
+                inner_class_info_index: inner_class_info_index as u16,
+                outer_class_info_index: outer_class_info_index as u16,
+                inner_name_index: inner_name_index as u16,
+                inner_class_access_flags: inner_class_access_flags as u16,
+            });
+            index = update_index;
+        }
+
+        (
+            InnerClasses {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes,
+                classes,
+            },
+            index,
+        )
+    }
+}
+
+impl fmt::Display for InnerClasses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut class_strs = Vec::with_capacity(self.number_of_classes);
+        for item in self.classes.iter() {
+            class_strs.push(format!("{}", item));
+        }
+        write!(
+            f,
+            "InnerClasses:
+  {}",
+            class_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct InnerClass {
+    pub inner_class_info_index: u16,   // u2
+    pub outer_class_info_index: u16,   // u2
+    pub inner_name_index: u16,         // u2
+    pub inner_class_access_flags: u16, // u2
+}
+
+impl fmt::Display for InnerClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{}= #{} of #{}; flags: {:#06x}",
+            self.inner_name_index,
+            self.inner_class_info_index,
+            self.outer_class_info_index,
+            self.inner_class_access_flags
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BootstrapMethods {
+    pub attribute_name_index: u16,    // u2
+    pub attribute_length: u32,        // u4
+    pub num_bootstrap_methods: usize, // u2
+    pub bootstrap_methods: Vec<BootstrapMethod>,
+}
+
+impl BootstrapMethods {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (BootstrapMethods, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (num_bootstrap_methods, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut bootstrap_methods = Vec::with_capacity(num_bootstrap_methods);
+        for _ in 0..num_bootstrap_methods {
+            let (bootstrap_method_ref, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (num_bootstrap_arguments, mut update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let mut bootstrap_arguments = Vec::with_capacity(num_bootstrap_arguments);
+            for _ in 0..num_bootstrap_arguments {
+                let (argument, argument_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+                bootstrap_arguments.push(argument);
+                update_index = argument_index;
+            }
+
+            bootstrap_methods.push(BootstrapMethod {
+                bootstrap_method_ref,
+                num_bootstrap_arguments,
+                bootstrap_arguments,
+            });
+            index = update_index;
+        }
+
+        (
+            BootstrapMethods {
+                attribute_name_index,
+                attribute_length,
+                num_bootstrap_methods,
+                bootstrap_methods,
+            },
+            index,
+        )
+    }
+}
+
+impl fmt::Display for BootstrapMethods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut method_strs = Vec::with_capacity(self.num_bootstrap_methods);
+        for (index, item) in self.bootstrap_methods.iter().enumerate() {
+            method_strs.push(format!("{}: {}", index, item));
+        }
+        write!(
+            f,
+            "BootstrapMethods:
+  {}",
+            method_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BootstrapMethod {
+    pub bootstrap_method_ref: usize,     // u2
+    pub num_bootstrap_arguments: usize,  // u2
+    pub bootstrap_arguments: Vec<usize>, // u2
+}
+
+impl fmt::Display for BootstrapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{}
+    Method arguments:
+      {}",
+            self.bootstrap_method_ref,
+            self.bootstrap_arguments
+                .iter()
+                .map(|index| format!("#{}", index))
+                .collect::<Vec<String>>()
+                .join("\n      ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Exceptions {
+    pub attribute_name_index: u16,       // u2
+    pub attribute_length: u32,           // u4
+    pub number_of_exceptions: usize,     // u2
+    pub exception_index_table: Vec<u16>, // u2
+}
+
+impl Exceptions {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Exceptions, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_exceptions, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut exception_index_table = Vec::with_capacity(number_of_exceptions);
+        for _ in 0..number_of_exceptions {
+            let (exception_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            exception_index_table.push(exception_index as u16);
+            index = update_index;
+        }
+
+        (
+            Exceptions {
+                attribute_name_index,
+                attribute_length,
+                number_of_exceptions,
+                exception_index_table,
+            },
+            index,
+        )
+    }
+}
+
+impl fmt::Display for Exceptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Exceptions:
+  throws {}",
+            self.exception_index_table
+                .iter()
+                .map(|index| format!("#{}", index))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct LineNumberTable {
     pub attribute_name_index: u16,       // u2
@@ -304,10 +686,10 @@ impl fmt::Display for StackMapTable {
 #[derive(Debug)]
 pub enum StackMapFrame {
     SameFrame(SameFrame),
-    SameLocals1StackItemFrame,
-    SameLocals1StackItemFrameExtended,
+    SameLocals1StackItemFrame(SameLocals1StackItemFrame),
+    SameLocals1StackItemFrameExtended(SameLocals1StackItemFrameExtended),
     ChopFrame(ChopFrame),
-    SameFrameExtended,
+    SameFrameExtended(SameFrameExtended),
     AppendFrame(AppendFrame),
     FullFrame(FullFrame),
 }
@@ -317,6 +699,25 @@ pub struct SameFrame {
     frame_type: usize,
 }
 
+#[derive(Debug)]
+pub struct SameLocals1StackItemFrame {
+    frame_type: usize,
+    stack: VerificationTypeInfo,
+}
+
+#[derive(Debug)]
+pub struct SameLocals1StackItemFrameExtended {
+    frame_type: usize,
+    offset_delta: usize,
+    stack: VerificationTypeInfo,
+}
+
+#[derive(Debug)]
+pub struct SameFrameExtended {
+    frame_type: usize,
+    offset_delta: usize,
+}
+
 #[derive(Debug)]
 pub struct ChopFrame {
     frame_type: usize,
@@ -345,6 +746,30 @@ impl StackMapFrame {
         let (frame_type, index) = extract_x_byte_as_usize(inputs, index, 1);
         match frame_type {
             0..=63 => (StackMapFrame::SameFrame(SameFrame { frame_type }), index),
+            64..=127 => {
+                let (mut stack, index) = extract_verification_type_info(inputs, index, 1);
+                (
+                    StackMapFrame::SameLocals1StackItemFrame(SameLocals1StackItemFrame {
+                        frame_type,
+                        stack: stack.remove(0),
+                    }),
+                    index,
+                )
+            }
+            247 => {
+                let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (mut stack, index) = extract_verification_type_info(inputs, index, 1);
+                (
+                    StackMapFrame::SameLocals1StackItemFrameExtended(
+                        SameLocals1StackItemFrameExtended {
+                            frame_type,
+                            offset_delta,
+                            stack: stack.remove(0),
+                        },
+                    ),
+                    index,
+                )
+            }
             248..=250 => {
                 let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
                 (
@@ -355,6 +780,16 @@ impl StackMapFrame {
                     index,
                 )
             }
+            251 => {
+                let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (
+                    StackMapFrame::SameFrameExtended(SameFrameExtended {
+                        frame_type,
+                        offset_delta,
+                    }),
+                    index,
+                )
+            }
             252..=254 => {
                 let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let length = (frame_type as i32) - 251;
@@ -405,6 +840,37 @@ impl fmt::Display for StackMapFrame {
             StackMapFrame::SameFrame(SameFrame { frame_type }) => {
                 write!(f, "{}   /* same */", frame_type)
             }
+            StackMapFrame::SameLocals1StackItemFrame(SameLocals1StackItemFrame {
+                frame_type,
+                stack,
+            }) => write!(
+                f,
+                "{}   /* same_locals_1_stack_item */
+    stack = [{}]",
+                frame_type, stack
+            ),
+            StackMapFrame::SameLocals1StackItemFrameExtended(
+                SameLocals1StackItemFrameExtended {
+                    frame_type,
+                    offset_delta,
+                    stack,
+                },
+            ) => write!(
+                f,
+                "{}   /* same_locals_1_stack_item_frame_extended */
+    offset_delta = {}
+    stack = [{}]",
+                frame_type, offset_delta, stack
+            ),
+            StackMapFrame::SameFrameExtended(SameFrameExtended {
+                frame_type,
+                offset_delta,
+            }) => write!(
+                f,
+                "{}   /* same_frame_extended */
+  offset_delta = {}",
+                frame_type, offset_delta
+            ),
             StackMapFrame::ChopFrame(ChopFrame {
                 frame_type,
                 offset_delta,
@@ -462,7 +928,6 @@ impl fmt::Display for StackMapFrame {
                         .join(", ")
                 )
             ),
-            _ => unimplemented!(),
         }
     }
 }
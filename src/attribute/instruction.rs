@@ -3,86 +3,160 @@ use std::fmt;
 
 #[derive(Debug)]
 pub enum Instruction {
-    Aconstnull,                                // 0x01
-    IconstN(i32),                              // 0x02(-1) - 0x08(5)
-    LconstN(usize),                            // 0x09(0) - 0x0a(1)
-    FconstN(f32),                              // 0x0b(0) - 0x0d(1)
-    DconstN(usize),                            // 0x0e(0) - 0x0f(1)
-    Bipush(i32),                               // 0x10
-    Sipush(usize),                             // 0x11
-    Ldc(usize),                                // 0x12
-    Ldc2W(usize, usize),                       // 0x14
-    Iload(usize),                              // 0x15
-    Aload(usize),                              // 0x19
-    IloadN(usize),                             // 0x1a(0) - 0x1d(3)
-    LloadN(usize),                             // 0x1e(0) - 0x21(3)
-    FloadN(usize),                             // 0x22(0) - 0x25(3)
-    DloadN(usize),                             // 0x26(0) - 0x29(3)
-    AloadN(usize),                             // 0x2a(0) - 0x2d(3)
-    Iaload,                                    // 0x2e
-    Laload,                                    // 0x2f
-    Aaload,                                    // 0x32
-    Baload,                                    // 0x33
-    Istore(i32),                               // 0x36
-    Astore(usize),                             // 0x3a
-    IstoreN(i32),                              // 0x3b(0) - 0x3e(3)
-    LstoreN(usize),                            // 0x3f(0) - 0x42(3)
-    FstoreN(usize),                            // 0x43(0) - 0x46(3)
-    DstoreN(usize),                            // 0x47(0) - 0x4a(3)
-    AstoreN(usize),                            // 0x4b(0) - 0x4e(3)
-    Iastore,                                   // 0x4f
-    Lastore,                                   // 0x50
-    Aastore,                                   // 0x53
-    Bastore,                                   // 0x54
-    Pop,                                       // 0x57
-    Dup,                                       // 0x59
-    Iadd,                                      // 0x60
-    Ladd,                                      // 0x61
-    Fadd,                                      // 0x62
-    Isub,                                      // 0x64
-    Lsub,                                      // 0x65
-    Fsub,                                      // 0x66
-    Imul,                                      // 0x68
-    Lmul,                                      // 0x69
-    Fmul,                                      // 0x6a
-    Idiv,                                      // 0x6c
-    Ldiv,                                      // 0x6d
-    Fdiv,                                      // 0x6e
-    Irem,                                      // 0x70
-    Lrem,                                      // 0x71
-    Iinc(usize, usize),                        // 0x84
-    Lcmp,                                      // 0x94
-    Fcmpg,                                     // 0x95
-    Fcmpl,                                     // 0x96
-    Ifeq(usize, usize),                        // 0x99
-    Ifne(usize, usize),                        // 0x9a
-    Iflt(usize, usize),                        // 0x9b
-    Ifge(usize, usize),                        // 0x9c
-    Ifgt(usize, usize),                        // 0x9d
-    Ifle(usize, usize),                        // 0x9e
-    Ificmpeq(usize, usize),                    // 0x9f
-    Ificmpne(usize, usize),                    // 0xa0
-    Ificmplt(usize, usize),                    // 0xa1
-    Ificmpge(usize, usize),                    // 0xa2
-    Ificmpgt(usize, usize),                    // 0xa3
-    Ificmple(usize, usize),                    // 0xa4
-    Goto(usize),                               // 0xa7
-    Lookupswitch(Vec<(Option<usize>, usize)>), // 0xab
-    Ireturn,                                   // 0xac
-    Areturn,                                   // 0xb0
-    Return,                                    // 0xb1
-    Getstatic(usize),                          // 0xb2
-    Putstatic(usize),                          // 0xb3
-    Getfield(usize),                           // 0xb4
-    Putfield(usize),                           // 0xb5
-    Invokevirtual(usize),                      // 0xb6
-    Invokespecial(usize),                      // 0xb7
-    Invokestatic(usize),                       // 0xb8
-    New(usize),                                // 0xbb
-    Newarray(usize),                           // 0xbc
-    Anewarray(usize),                          // 0xbd
-    Multianewarray(usize, usize),              // 0xc5
-    Noope,                                     // custom command for Ificmple etc.
+    Aconstnull,                             // 0x01
+    IconstN(i32),                           // 0x02(-1) - 0x08(5)
+    LconstN(usize),                         // 0x09(0) - 0x0a(1)
+    FconstN(f32),                           // 0x0b(0) - 0x0d(1)
+    DconstN(usize),                         // 0x0e(0) - 0x0f(1)
+    Bipush(i32),                            // 0x10
+    Sipush(i32),                            // 0x11
+    Ldc(usize),                             // 0x12
+    LdcW(usize),                            // 0x13
+    Ldc2W(usize, usize),                    // 0x14
+    Iload(usize),                           // 0x15
+    Lload(usize),                           // 0x16
+    Fload(usize),                           // 0x17
+    Dload(usize),                           // 0x18
+    Aload(usize),                           // 0x19
+    IloadN(usize),                          // 0x1a(0) - 0x1d(3)
+    LloadN(usize),                          // 0x1e(0) - 0x21(3)
+    FloadN(usize),                          // 0x22(0) - 0x25(3)
+    DloadN(usize),                          // 0x26(0) - 0x29(3)
+    AloadN(usize),                          // 0x2a(0) - 0x2d(3)
+    Iaload,                                 // 0x2e
+    Laload,                                 // 0x2f
+    Faload,                                 // 0x30
+    Daload,                                 // 0x31
+    Aaload,                                 // 0x32
+    Baload,                                 // 0x33
+    Caload,                                 // 0x34
+    Saload,                                 // 0x35
+    Istore(i32),                            // 0x36
+    Lstore(usize),                          // 0x37
+    Fstore(usize),                          // 0x38
+    Dstore(usize),                          // 0x39
+    Astore(usize),                          // 0x3a
+    IstoreN(i32),                           // 0x3b(0) - 0x3e(3)
+    LstoreN(usize),                         // 0x3f(0) - 0x42(3)
+    FstoreN(usize),                         // 0x43(0) - 0x46(3)
+    DstoreN(usize),                         // 0x47(0) - 0x4a(3)
+    AstoreN(usize),                         // 0x4b(0) - 0x4e(3)
+    Iastore,                                // 0x4f
+    Lastore,                                // 0x50
+    Fastore,                                // 0x51
+    Dastore,                                // 0x52
+    Aastore,                                // 0x53
+    Bastore,                                // 0x54
+    Castore,                                // 0x55
+    Sastore,                                // 0x56
+    Pop,                                    // 0x57
+    Pop2,                                   // 0x58
+    Dup,                                    // 0x59
+    DupX1,                                  // 0x5a
+    DupX2,                                  // 0x5b
+    Dup2,                                   // 0x5c
+    Dup2X1,                                 // 0x5d
+    Dup2X2,                                 // 0x5e
+    Swap,                                   // 0x5f
+    Iadd,                                   // 0x60
+    Ladd,                                   // 0x61
+    Fadd,                                   // 0x62
+    Dadd,                                   // 0x63
+    Isub,                                   // 0x64
+    Lsub,                                   // 0x65
+    Fsub,                                   // 0x66
+    Dsub,                                   // 0x67
+    Imul,                                   // 0x68
+    Lmul,                                   // 0x69
+    Fmul,                                   // 0x6a
+    Dmul,                                   // 0x6b
+    Idiv,                                   // 0x6c
+    Ldiv,                                   // 0x6d
+    Fdiv,                                   // 0x6e
+    Ddiv,                                   // 0x6f
+    Irem,                                   // 0x70
+    Lrem,                                   // 0x71
+    Drem,                                   // 0x73
+    Ineg,                                   // 0x74
+    Lneg,                                   // 0x75
+    Fneg,                                   // 0x76
+    Dneg,                                   // 0x77
+    Ishl,                                   // 0x78
+    Lshl,                                   // 0x79
+    Ishr,                                   // 0x7a
+    Lshr,                                   // 0x7b
+    Iushr,                                  // 0x7c
+    Lushr,                                  // 0x7d
+    Iand,                                   // 0x7e
+    Land,                                   // 0x7f
+    Ior,                                    // 0x80
+    Lor,                                    // 0x81
+    Ixor,                                   // 0x82
+    Lxor,                                   // 0x83
+    Iinc(usize, i32),                       // 0x84
+    I2l,                                    // 0x85
+    I2f,                                    // 0x86
+    I2d,                                    // 0x87
+    L2i,                                    // 0x88
+    L2f,                                    // 0x89
+    L2d,                                    // 0x8a
+    F2i,                                    // 0x8b
+    F2l,                                    // 0x8c
+    F2d,                                    // 0x8d
+    D2i,                                    // 0x8e
+    D2l,                                    // 0x8f
+    D2f,                                    // 0x90
+    I2b,                                    // 0x91
+    I2c,                                    // 0x92
+    I2s,                                    // 0x93
+    Lcmp,                                   // 0x94
+    Fcmpg,                                  // 0x95
+    Fcmpl,                                  // 0x96
+    Dcmpl,                                  // 0x97
+    Dcmpg,                                  // 0x98
+    Ifeq(usize, usize),                     // 0x99
+    Ifne(usize, usize),                     // 0x9a
+    Iflt(usize, usize),                     // 0x9b
+    Ifge(usize, usize),                     // 0x9c
+    Ifgt(usize, usize),                     // 0x9d
+    Ifle(usize, usize),                     // 0x9e
+    Ificmpeq(usize, usize),                 // 0x9f
+    Ificmpne(usize, usize),                 // 0xa0
+    Ificmplt(usize, usize),                 // 0xa1
+    Ificmpge(usize, usize),                 // 0xa2
+    Ificmpgt(usize, usize),                 // 0xa3
+    Ificmple(usize, usize),                 // 0xa4
+    Ifacmpeq(usize, usize),                 // 0xa5
+    Ifacmpne(usize, usize),                 // 0xa6
+    Goto(usize),                            // 0xa7
+    Tableswitch(usize, i32, Vec<usize>),    // 0xaa default, low, jump pointers
+    Lookupswitch(usize, Vec<(i32, usize)>), // 0xab default, (key, jump pointer)
+    Ireturn,                                // 0xac
+    Lreturn,                                // 0xad
+    Freturn,                                // 0xae
+    Dreturn,                                // 0xaf
+    Areturn,                                // 0xb0
+    Return,                                 // 0xb1
+    Getstatic(usize),                       // 0xb2
+    Putstatic(usize),                       // 0xb3
+    Getfield(usize),                        // 0xb4
+    Putfield(usize),                        // 0xb5
+    Invokevirtual(usize),                   // 0xb6
+    Invokespecial(usize),                   // 0xb7
+    Invokestatic(usize),                    // 0xb8
+    Invokeinterface(usize, usize),          // 0xb9
+    Invokedynamic(usize),                   // 0xba
+    New(usize),                             // 0xbb
+    Newarray(usize),                        // 0xbc
+    Anewarray(usize),                       // 0xbd
+    Arraylength,                            // 0xbe
+    Athrow,                                 // 0xbf
+    Checkcast(usize),                       // 0xc0
+    Instanceof(usize),                      // 0xc1
+    Multianewarray(usize, usize),           // 0xc5
+    Ifnull(usize, usize),                   // 0xc6
+    Ifnonnull(usize, usize),                // 0xc7
+    Noope,                                  // custom command for Ificmple etc.
 }
 
 impl fmt::Display for Instruction {
@@ -96,8 +170,12 @@ impl fmt::Display for Instruction {
             Instruction::Bipush(val) => write!(f, "bipush         {}", val),
             Instruction::Sipush(val) => write!(f, "sipush         {}", val),
             Instruction::Ldc(val) => write!(f, "ldc             #{}", val),
+            Instruction::LdcW(val) => write!(f, "ldc_w           #{}", val),
             Instruction::Ldc2W(a, b) => write!(f, "ldc2_w         #{},{}", a, b),
             Instruction::Iload(val) => write!(f, "iload            #{}", val),
+            Instruction::Lload(val) => write!(f, "lload            #{}", val),
+            Instruction::Fload(val) => write!(f, "fload            #{}", val),
+            Instruction::Dload(val) => write!(f, "dload            #{}", val),
             Instruction::Aload(val) => write!(f, "aload            #{}", val),
             Instruction::IloadN(val) => write!(f, "iload_{}", val),
             Instruction::LloadN(val) => write!(f, "lload_{}", val),
@@ -106,39 +184,95 @@ impl fmt::Display for Instruction {
             Instruction::AloadN(val) => write!(f, "aload_{}", val),
             Instruction::Iaload => write!(f, "iaload"),
             Instruction::Laload => write!(f, "laload"),
+            Instruction::Faload => write!(f, "faload"),
+            Instruction::Daload => write!(f, "daload"),
             Instruction::Aaload => write!(f, "aaload"),
             Instruction::Baload => write!(f, "baload"),
+            Instruction::Caload => write!(f, "caload"),
+            Instruction::Saload => write!(f, "saload"),
             Instruction::Istore(val) => write!(f, "istore            #{}", val),
+            Instruction::Lstore(val) => write!(f, "lstore            #{}", val),
+            Instruction::Fstore(val) => write!(f, "fstore            #{}", val),
+            Instruction::Dstore(val) => write!(f, "dstore            #{}", val),
             Instruction::Astore(val) => write!(f, "astore            #{}", val),
             Instruction::Aastore => write!(f, "aastore"),
             Instruction::Bastore => write!(f, "bastore"),
+            Instruction::Castore => write!(f, "castore"),
+            Instruction::Sastore => write!(f, "sastore"),
             Instruction::IstoreN(val) => write!(f, "istore_{}", val),
             Instruction::LstoreN(val) => write!(f, "lstore_{}", val),
             Instruction::FstoreN(val) => write!(f, "fstore_{}", val),
             Instruction::DstoreN(val) => write!(f, "dstore_{}", val),
             Instruction::Iastore => write!(f, "iastore"),
             Instruction::Lastore => write!(f, "lastore"),
+            Instruction::Fastore => write!(f, "fastore"),
+            Instruction::Dastore => write!(f, "dastore"),
             Instruction::AstoreN(val) => write!(f, "astore_{}", val),
             Instruction::Pop => write!(f, "pop"),
+            Instruction::Pop2 => write!(f, "pop2"),
             Instruction::Dup => write!(f, "dup"),
+            Instruction::DupX1 => write!(f, "dup_x1"),
+            Instruction::DupX2 => write!(f, "dup_x2"),
+            Instruction::Dup2 => write!(f, "dup2"),
+            Instruction::Dup2X1 => write!(f, "dup2_x1"),
+            Instruction::Dup2X2 => write!(f, "dup2_x2"),
+            Instruction::Swap => write!(f, "swap"),
             Instruction::Iadd => write!(f, "iadd"),
             Instruction::Ladd => write!(f, "ladd"),
             Instruction::Fadd => write!(f, "fadd"),
+            Instruction::Dadd => write!(f, "dadd"),
             Instruction::Isub => write!(f, "isub"),
             Instruction::Lsub => write!(f, "lsub"),
             Instruction::Fsub => write!(f, "fsub"),
+            Instruction::Dsub => write!(f, "dsub"),
             Instruction::Imul => write!(f, "imul"),
             Instruction::Lmul => write!(f, "lmul"),
             Instruction::Fmul => write!(f, "fmul"),
+            Instruction::Dmul => write!(f, "dmul"),
             Instruction::Idiv => write!(f, "idiv"),
             Instruction::Ldiv => write!(f, "ldiv"),
             Instruction::Fdiv => write!(f, "fdiv"),
+            Instruction::Ddiv => write!(f, "ddiv"),
             Instruction::Irem => write!(f, "irem"),
             Instruction::Lrem => write!(f, "lrem"),
+            Instruction::Drem => write!(f, "drem"),
+            Instruction::Ineg => write!(f, "ineg"),
+            Instruction::Lneg => write!(f, "lneg"),
+            Instruction::Fneg => write!(f, "fneg"),
+            Instruction::Dneg => write!(f, "dneg"),
+            Instruction::Ishl => write!(f, "ishl"),
+            Instruction::Lshl => write!(f, "lshl"),
+            Instruction::Ishr => write!(f, "ishr"),
+            Instruction::Lshr => write!(f, "lshr"),
+            Instruction::Iushr => write!(f, "iushr"),
+            Instruction::Lushr => write!(f, "lushr"),
+            Instruction::Iand => write!(f, "iand"),
+            Instruction::Land => write!(f, "land"),
+            Instruction::Ior => write!(f, "ior"),
+            Instruction::Lor => write!(f, "lor"),
+            Instruction::Ixor => write!(f, "ixor"),
+            Instruction::Lxor => write!(f, "lxor"),
             Instruction::Iinc(a, b) => write!(f, "iinc        {}, {}", a, b),
+            Instruction::I2l => write!(f, "i2l"),
+            Instruction::I2f => write!(f, "i2f"),
+            Instruction::I2d => write!(f, "i2d"),
+            Instruction::L2i => write!(f, "l2i"),
+            Instruction::L2f => write!(f, "l2f"),
+            Instruction::L2d => write!(f, "l2d"),
+            Instruction::F2i => write!(f, "f2i"),
+            Instruction::F2l => write!(f, "f2l"),
+            Instruction::F2d => write!(f, "f2d"),
+            Instruction::D2i => write!(f, "d2i"),
+            Instruction::D2l => write!(f, "d2l"),
+            Instruction::D2f => write!(f, "d2f"),
+            Instruction::I2b => write!(f, "i2b"),
+            Instruction::I2c => write!(f, "i2c"),
+            Instruction::I2s => write!(f, "i2s"),
             Instruction::Lcmp => write!(f, "lcmp"),
             Instruction::Fcmpg => write!(f, "fcmpg"),
             Instruction::Fcmpl => write!(f, "fcmpl"),
+            Instruction::Dcmpl => write!(f, "dcmpl"),
+            Instruction::Dcmpg => write!(f, "dcmpg"),
             Instruction::Ifeq(a, b) => write!(f, "if_eq       {}, {}", a, b),
             Instruction::Ifne(a, b) => write!(f, "if_ne       {}, {}", a, b),
             Instruction::Iflt(a, b) => write!(f, "if_lt       {}, {}", a, b),
@@ -151,21 +285,47 @@ impl fmt::Display for Instruction {
             Instruction::Ificmpge(a, b) => write!(f, "if_icmpge   {}, {}", a, b),
             Instruction::Ificmpgt(a, b) => write!(f, "if_icmpgt   {}, {}", a, b),
             Instruction::Ificmple(a, b) => write!(f, "if_icmple   {}, {}", a, b),
+            Instruction::Ifacmpeq(a, b) => write!(f, "if_acmpeq   {}, {}", a, b),
+            Instruction::Ifacmpne(a, b) => write!(f, "if_acmpne   {}, {}", a, b),
+            Instruction::Ifnull(a, b) => write!(f, "ifnull      {}, {}", a, b),
+            Instruction::Ifnonnull(a, b) => write!(f, "ifnonnull   {}, {}", a, b),
             Instruction::Goto(val) => write!(f, "goto          {}", val),
             Instruction::Ireturn => write!(f, "ireturn"),
-            Instruction::Lookupswitch(vals) => {
-                let vals_length = vals.len();
-                let mut output_strings = Vec::with_capacity(vals_length);
-                for (key, val) in &vals[1..vals_length] {
-                    output_strings.push(format!("       {}: {}", key.unwrap(), val));
+            Instruction::Lreturn => write!(f, "lreturn"),
+            Instruction::Freturn => write!(f, "freturn"),
+            Instruction::Dreturn => write!(f, "dreturn"),
+            Instruction::Tableswitch(default, low, jump_pointers) => {
+                let mut output_strings = Vec::with_capacity(jump_pointers.len() + 1);
+                for (offset, jump_pointer) in jump_pointers.iter().enumerate() {
+                    output_strings.push(format!(
+                        "       {}: {}",
+                        low + offset as i32,
+                        jump_pointer
+                    ));
                 }
-                output_strings.push(format!("       default: {}", vals.last().unwrap().1));
+                output_strings.push(format!("       default: {}", default));
+                write!(
+                    f,
+                    "tableswitch {{ // {} to {}
+{}
+}}",
+                    low,
+                    low + jump_pointers.len() as i32 - 1,
+                    output_strings.join("\n")
+                )
+            }
+            Instruction::Lookupswitch(default, pairs) => {
+                let mut output_strings = Vec::with_capacity(pairs.len() + 1);
+                for (key, jump_pointer) in pairs {
+                    output_strings.push(format!("       {}: {}", key, jump_pointer));
+                }
+                output_strings.push(format!("       default: {}", default));
                 write!(
                     f,
                     "lookupswitch {{ // {}
 {}
 }}",
-                    vals_length - 1,
+                    pairs.len(),
                     output_strings.join("\n")
                 )
             }
@@ -178,9 +338,17 @@ impl fmt::Display for Instruction {
             Instruction::Invokevirtual(val) => write!(f, "invokevirtual   #{}", val),
             Instruction::Invokespecial(val) => write!(f, "invokespecial   #{}", val),
             Instruction::Invokestatic(val) => write!(f, "invokestatic   #{}", val),
+            Instruction::Invokeinterface(index, count) => {
+                write!(f, "invokeinterface #{},  {}", index, count)
+            }
+            Instruction::Invokedynamic(val) => write!(f, "invokedynamic  #{},  0", val),
             Instruction::New(val) => write!(f, "new            #{}", val),
             Instruction::Newarray(val) => write!(f, "newarray       #{}", val),
             Instruction::Anewarray(val) => write!(f, "anewarray      #{}", val),
+            Instruction::Arraylength => write!(f, "arraylength"),
+            Instruction::Athrow => write!(f, "athrow"),
+            Instruction::Checkcast(val) => write!(f, "checkcast      #{}", val),
+            Instruction::Instanceof(val) => write!(f, "instanceof     #{}", val),
             Instruction::Multianewarray(index, dimensions) => {
                 write!(f, "multianewarray    #{} {}", index, dimensions)
             }
@@ -227,19 +395,14 @@ impl Instruction {
             // bipush
             0x10 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
-                let val = if val > 0x79 {
-                    -1 * ((val ^ 0xff) + 1) as i32
-                } else {
-                    val as i32
-                };
-                codes.push(Instruction::Bipush(val));
+                codes.push(Instruction::Bipush(val as u8 as i8 as i32));
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
             // sipush
             0x11 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
-                codes.push(Instruction::Sipush(val));
+                codes.push(Instruction::Sipush(val as u16 as i16 as i32));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
                 (index, 3)
@@ -251,6 +414,14 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // ldc_w
+            0x13 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::LdcW(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
             // ldc2_w
             0x14 => {
                 let (val, index) = extract_x_byte_as_vec(inputs, index, 2);
@@ -266,6 +437,27 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // lload
+            0x16 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Lload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // fload
+            0x17 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Fload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // dload
+            0x18 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Dload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
             // aload
             0x19 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -301,7 +493,15 @@ impl Instruction {
             0x2f => {
                 simple_instruct!(Instruction::Laload);
             }
-            // iaload
+            // faload
+            0x30 => {
+                simple_instruct!(Instruction::Faload);
+            }
+            // daload
+            0x31 => {
+                simple_instruct!(Instruction::Daload);
+            }
+            // aaload
             0x32 => {
                 simple_instruct!(Instruction::Aaload);
             }
@@ -309,6 +509,14 @@ impl Instruction {
             0x33 => {
                 simple_instruct!(Instruction::Baload);
             }
+            // caload
+            0x34 => {
+                simple_instruct!(Instruction::Caload);
+            }
+            // saload
+            0x35 => {
+                simple_instruct!(Instruction::Saload);
+            }
             // istore
             0x36 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -316,6 +524,27 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // lstore
+            0x37 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Lstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // fstore
+            0x38 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Fstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // dstore
+            0x39 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Dstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
             // astore
             0x3a => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -351,6 +580,14 @@ impl Instruction {
             0x50 => {
                 simple_instruct!(Instruction::Lastore);
             }
+            // fastore
+            0x51 => {
+                simple_instruct!(Instruction::Fastore);
+            }
+            // dastore
+            0x52 => {
+                simple_instruct!(Instruction::Dastore);
+            }
             // aastore
             0x53 => {
                 simple_instruct!(Instruction::Aastore);
@@ -359,14 +596,50 @@ impl Instruction {
             0x54 => {
                 simple_instruct!(Instruction::Bastore);
             }
+            // castore
+            0x55 => {
+                simple_instruct!(Instruction::Castore);
+            }
+            // sastore
+            0x56 => {
+                simple_instruct!(Instruction::Sastore);
+            }
             // pop
             0x57 => {
                 simple_instruct!(Instruction::Pop);
             }
+            // pop2
+            0x58 => {
+                simple_instruct!(Instruction::Pop2);
+            }
             // dup
             0x59 => {
                 simple_instruct!(Instruction::Dup);
             }
+            // dup_x1
+            0x5a => {
+                simple_instruct!(Instruction::DupX1);
+            }
+            // dup_x2
+            0x5b => {
+                simple_instruct!(Instruction::DupX2);
+            }
+            // dup2
+            0x5c => {
+                simple_instruct!(Instruction::Dup2);
+            }
+            // dup2_x1
+            0x5d => {
+                simple_instruct!(Instruction::Dup2X1);
+            }
+            // dup2_x2
+            0x5e => {
+                simple_instruct!(Instruction::Dup2X2);
+            }
+            // swap
+            0x5f => {
+                simple_instruct!(Instruction::Swap);
+            }
             // iadd
             0x60 => {
                 simple_instruct!(Instruction::Iadd);
@@ -379,6 +652,10 @@ impl Instruction {
             0x62 => {
                 simple_instruct!(Instruction::Fadd);
             }
+            // dadd
+            0x63 => {
+                simple_instruct!(Instruction::Dadd);
+            }
             // isub
             0x64 => {
                 simple_instruct!(Instruction::Isub);
@@ -391,6 +668,10 @@ impl Instruction {
             0x66 => {
                 simple_instruct!(Instruction::Fsub);
             }
+            // dsub
+            0x67 => {
+                simple_instruct!(Instruction::Dsub);
+            }
             // imul
             0x68 => {
                 simple_instruct!(Instruction::Imul);
@@ -403,6 +684,10 @@ impl Instruction {
             0x6a => {
                 simple_instruct!(Instruction::Fmul);
             }
+            // dmul
+            0x6b => {
+                simple_instruct!(Instruction::Dmul);
+            }
             // idiv
             0x6c => {
                 simple_instruct!(Instruction::Idiv);
@@ -415,6 +700,10 @@ impl Instruction {
             0x6e => {
                 simple_instruct!(Instruction::Fdiv);
             }
+            // ddiv
+            0x6f => {
+                simple_instruct!(Instruction::Ddiv);
+            }
             // irem
             0x70 => {
                 simple_instruct!(Instruction::Irem);
@@ -423,14 +712,142 @@ impl Instruction {
             0x71 => {
                 simple_instruct!(Instruction::Lrem);
             }
+            // drem
+            0x73 => {
+                simple_instruct!(Instruction::Drem);
+            }
+            // ineg
+            0x74 => {
+                simple_instruct!(Instruction::Ineg);
+            }
+            // lneg
+            0x75 => {
+                simple_instruct!(Instruction::Lneg);
+            }
+            // fneg
+            0x76 => {
+                simple_instruct!(Instruction::Fneg);
+            }
+            // dneg
+            0x77 => {
+                simple_instruct!(Instruction::Dneg);
+            }
+            // ishl
+            0x78 => {
+                simple_instruct!(Instruction::Ishl);
+            }
+            // lshl
+            0x79 => {
+                simple_instruct!(Instruction::Lshl);
+            }
+            // ishr
+            0x7a => {
+                simple_instruct!(Instruction::Ishr);
+            }
+            // lshr
+            0x7b => {
+                simple_instruct!(Instruction::Lshr);
+            }
+            // iushr
+            0x7c => {
+                simple_instruct!(Instruction::Iushr);
+            }
+            // lushr
+            0x7d => {
+                simple_instruct!(Instruction::Lushr);
+            }
+            // iand
+            0x7e => {
+                simple_instruct!(Instruction::Iand);
+            }
+            // land
+            0x7f => {
+                simple_instruct!(Instruction::Land);
+            }
+            // ior
+            0x80 => {
+                simple_instruct!(Instruction::Ior);
+            }
+            // lor
+            0x81 => {
+                simple_instruct!(Instruction::Lor);
+            }
+            // ixor
+            0x82 => {
+                simple_instruct!(Instruction::Ixor);
+            }
+            // lxor
+            0x83 => {
+                simple_instruct!(Instruction::Lxor);
+            }
             // iinc
             0x84 => {
                 let (val, index) = extract_x_byte_as_vec(inputs, index, 2);
-                codes.push(Instruction::Iinc(val[0] as usize, val[1] as usize));
+                codes.push(Instruction::Iinc(val[0] as usize, val[1] as i8 as i32));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // i2l
+            0x85 => {
+                simple_instruct!(Instruction::I2l);
+            }
+            // i2f
+            0x86 => {
+                simple_instruct!(Instruction::I2f);
+            }
+            // i2d
+            0x87 => {
+                simple_instruct!(Instruction::I2d);
+            }
+            // l2i
+            0x88 => {
+                simple_instruct!(Instruction::L2i);
+            }
+            // l2f
+            0x89 => {
+                simple_instruct!(Instruction::L2f);
+            }
+            // l2d
+            0x8a => {
+                simple_instruct!(Instruction::L2d);
+            }
+            // f2i
+            0x8b => {
+                simple_instruct!(Instruction::F2i);
+            }
+            // f2l
+            0x8c => {
+                simple_instruct!(Instruction::F2l);
+            }
+            // f2d
+            0x8d => {
+                simple_instruct!(Instruction::F2d);
+            }
+            // d2i
+            0x8e => {
+                simple_instruct!(Instruction::D2i);
+            }
+            // d2l
+            0x8f => {
+                simple_instruct!(Instruction::D2l);
+            }
+            // d2f
+            0x90 => {
+                simple_instruct!(Instruction::D2f);
+            }
+            // i2b
+            0x91 => {
+                simple_instruct!(Instruction::I2b);
+            }
+            // i2c
+            0x92 => {
+                simple_instruct!(Instruction::I2c);
+            }
+            // i2s
+            0x93 => {
+                simple_instruct!(Instruction::I2s);
+            }
             // lcmp
             0x94 => {
                 simple_instruct!(Instruction::Lcmp);
@@ -443,6 +860,14 @@ impl Instruction {
             0x96 => {
                 simple_instruct!(Instruction::Fcmpl);
             }
+            // dcmpl
+            0x97 => {
+                simple_instruct!(Instruction::Dcmpl);
+            }
+            // dcmpg
+            0x98 => {
+                simple_instruct!(Instruction::Dcmpg);
+            }
             // ifeq
             0x99 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -587,6 +1012,30 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // if_acmpeq
+            0xa5 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifacmpeq(
+                    (val + code_length - 1) & 0xffff,
+                    code_length + 2,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // if_acmpne
+            0xa6 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifacmpne(
+                    (val + code_length - 1) & 0xffff,
+                    code_length + 2,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
             // goto
             0xa7 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -596,34 +1045,76 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // tableswitch
+            0xaa => {
+                // the operands start at an address that is a multiple of four
+                let code_length = codes.len();
+                let padding = (4 - (code_length + 1) % 4) % 4;
+                let (default, index) = extract_x_byte_as_usize(inputs, index + padding, 4);
+                let (low, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (high, mut index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (low, high) = (low as u32 as i32, high as u32 as i32);
+
+                let mut jump_pointers = Vec::with_capacity((high - low + 1) as usize);
+                for _ in low..=high {
+                    let (offset, update_index) = extract_x_byte_as_usize(inputs, index, 4);
+                    jump_pointers.push(to_jump_pointer(code_length, offset));
+                    index = update_index;
+                }
+
+                let consumed_length = 1 + padding + 12 + jump_pointers.len() * 4;
+                codes.push(Instruction::Tableswitch(
+                    to_jump_pointer(code_length, default),
+                    low,
+                    jump_pointers,
+                ));
+                for _ in 1..consumed_length {
+                    codes.push(Instruction::Noope);
+                }
+                (index, consumed_length)
+            }
             // lookupswitch
             0xab => {
-                let (offset, index) = extract_x_byte_as_usize(inputs, index, 4);
-                // default_value can be used for branch_length
-                let (default_value, mut index) = extract_x_byte_as_usize(inputs, index, 4);
-                // default + branch_length
-                let mut switch_values = Vec::with_capacity(1 + default_value);
-                switch_values.push((None, offset + default_value));
+                // the operands start at an address that is a multiple of four
+                let code_length = codes.len();
+                let padding = (4 - (code_length + 1) % 4) % 4;
+                let (default, index) = extract_x_byte_as_usize(inputs, index + padding, 4);
+                let (npairs, mut index) = extract_x_byte_as_usize(inputs, index, 4);
 
-                for _ in 0..default_value {
+                let mut pairs = Vec::with_capacity(npairs);
+                for _ in 0..npairs {
                     let (key, update_index) = extract_x_byte_as_usize(inputs, index, 4);
-                    let (val, update_index) = extract_x_byte_as_usize(inputs, update_index, 4);
-                    switch_values.push((Some(key), val + default_value));
-                    index = update_index
+                    let (offset, update_index) = extract_x_byte_as_usize(inputs, update_index, 4);
+                    pairs.push((key as u32 as i32, to_jump_pointer(code_length, offset)));
+                    index = update_index;
                 }
-                codes.push(Instruction::Lookupswitch(switch_values));
 
-                let set_length = default_value + 1;
-                let switch_instructions_len = set_length * 4 * 2;
-                for _ in 0..switch_instructions_len {
-                    codes.push(Instruction::Noope)
+                let consumed_length = 1 + padding + 8 + npairs * 8;
+                codes.push(Instruction::Lookupswitch(
+                    to_jump_pointer(code_length, default),
+                    pairs,
+                ));
+                for _ in 1..consumed_length {
+                    codes.push(Instruction::Noope);
                 }
-                (index, switch_instructions_len + 1)
+                (index, consumed_length)
             }
             // ireturn
             0xac => {
                 simple_instruct!(Instruction::Ireturn);
             }
+            // lreturn
+            0xad => {
+                simple_instruct!(Instruction::Lreturn);
+            }
+            // freturn
+            0xae => {
+                simple_instruct!(Instruction::Freturn);
+            }
+            // dreturn
+            0xaf => {
+                simple_instruct!(Instruction::Dreturn);
+            }
             // areturn
             0xb0 => {
                 simple_instruct!(Instruction::Areturn);
@@ -688,6 +1179,31 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // invokeinterface
+            0xb9 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (count, index) = extract_x_byte_as_usize(inputs, index, 1);
+                // the last byte is always 0
+                let (_, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Invokeinterface(val, count));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 5)
+            }
+            // invokedynamic
+            0xba => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                // the last two bytes are always 0
+                let (_, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::Invokedynamic(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 5)
+            }
             // new
             0xbb => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -711,6 +1227,59 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // arraylength
+            0xbe => {
+                simple_instruct!(Instruction::Arraylength);
+            }
+            // athrow
+            0xbf => {
+                simple_instruct!(Instruction::Athrow);
+            }
+            // checkcast
+            0xc0 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::Checkcast(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // instanceof
+            0xc1 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::Instanceof(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // wide
+            0xc4 => {
+                let (opcode, index) = extract_x_byte_as_usize(inputs, index, 1);
+                let (local_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (instruction, index, consumed_length) = match opcode {
+                    // iinc
+                    0x84 => {
+                        let (value, index) = extract_x_byte_as_usize(inputs, index, 2);
+                        let value = value as u16 as i16 as i32;
+                        (Instruction::Iinc(local_index, value), index, 6)
+                    }
+                    0x15 => (Instruction::Iload(local_index), index, 4),
+                    0x16 => (Instruction::Lload(local_index), index, 4),
+                    0x17 => (Instruction::Fload(local_index), index, 4),
+                    0x18 => (Instruction::Dload(local_index), index, 4),
+                    0x19 => (Instruction::Aload(local_index), index, 4),
+                    0x36 => (Instruction::Istore(local_index as i32), index, 4),
+                    0x37 => (Instruction::Lstore(local_index), index, 4),
+                    0x38 => (Instruction::Fstore(local_index), index, 4),
+                    0x39 => (Instruction::Dstore(local_index), index, 4),
+                    0x3a => (Instruction::Astore(local_index), index, 4),
+                    _ => unimplemented!("wide tag: {:x}", opcode),
+                };
+                codes.push(instruction);
+                for _ in 1..consumed_length {
+                    codes.push(Instruction::Noope);
+                }
+                (index, consumed_length)
+            }
             // multianewarray
             0xc5 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -721,21 +1290,49 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 4)
             }
+            // ifnull
+            0xc6 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifnull(
+                    (val + code_length - 1) & 0xffff,
+                    code_length + 2,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // ifnonnull
+            0xc7 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifnonnull(
+                    (val + code_length - 1) & 0xffff,
+                    code_length + 2,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
             _ => unimplemented!("tag: {:x}", tag),
         }
     }
 
     pub fn counsume_index(&self) -> usize {
         match self {
-            Instruction::Lookupswitch(vals) => vals.len() * 4,
+            Instruction::Tableswitch(_, _, _) | Instruction::Lookupswitch(_, _) => 0,
+            Instruction::Invokeinterface(_, _) | Instruction::Invokedynamic(_) => 4,
             Instruction::Multianewarray(_, _) => 3,
             Instruction::Ificmple(_, _)
             | Instruction::Getstatic(_)
             | Instruction::Putstatic(_)
             | Instruction::Getfield(_)
+            | Instruction::Checkcast(_)
+            | Instruction::Instanceof(_)
             | Instruction::Putfield(_)
             | Instruction::Iinc(_, _)
             | Instruction::Sipush(_)
+            | Instruction::LdcW(_)
             | Instruction::Ldc2W(_, _)
             | Instruction::Invokevirtual(_)
             | Instruction::Invokespecial(_)
@@ -743,8 +1340,14 @@ impl Instruction {
             | Instruction::New(_)
             | Instruction::Anewarray(_) => 2,
             Instruction::Iload(_)
+            | Instruction::Lload(_)
+            | Instruction::Fload(_)
+            | Instruction::Dload(_)
             | Instruction::Aload(_)
             | Instruction::Istore(_)
+            | Instruction::Lstore(_)
+            | Instruction::Fstore(_)
+            | Instruction::Dstore(_)
             | Instruction::Astore(_)
             | Instruction::Bipush(_)
             | Instruction::Newarray(_)
@@ -765,36 +1368,101 @@ impl Instruction {
             | Instruction::AstoreN(_)
             | Instruction::AloadN(_)
             | Instruction::Pop
+            | Instruction::Pop2
             | Instruction::Dup
+            | Instruction::DupX1
+            | Instruction::DupX2
+            | Instruction::Dup2
+            | Instruction::Dup2X1
+            | Instruction::Dup2X2
+            | Instruction::Swap
             | Instruction::Iadd
             | Instruction::Ladd
             | Instruction::Fadd
+            | Instruction::Dadd
             | Instruction::Isub
             | Instruction::Lsub
             | Instruction::Fsub
+            | Instruction::Dsub
             | Instruction::Imul
             | Instruction::Lmul
             | Instruction::Fmul
+            | Instruction::Dmul
             | Instruction::Idiv
             | Instruction::Ldiv
             | Instruction::Fdiv
+            | Instruction::Ddiv
             | Instruction::Irem
             | Instruction::Lrem
+            | Instruction::Drem
+            | Instruction::Ineg
+            | Instruction::Lneg
+            | Instruction::Fneg
+            | Instruction::Dneg
+            | Instruction::Ishl
+            | Instruction::Lshl
+            | Instruction::Ishr
+            | Instruction::Lshr
+            | Instruction::Iushr
+            | Instruction::Lushr
+            | Instruction::Iand
+            | Instruction::Land
+            | Instruction::Ior
+            | Instruction::Lor
+            | Instruction::Ixor
+            | Instruction::Lxor
+            | Instruction::I2l
+            | Instruction::I2f
+            | Instruction::I2d
+            | Instruction::L2i
+            | Instruction::L2f
+            | Instruction::L2d
+            | Instruction::F2i
+            | Instruction::F2l
+            | Instruction::F2d
+            | Instruction::D2i
+            | Instruction::D2l
+            | Instruction::D2f
+            | Instruction::I2b
+            | Instruction::I2c
+            | Instruction::I2s
             | Instruction::Lcmp
             | Instruction::Fcmpg
             | Instruction::Fcmpl
+            | Instruction::Dcmpl
+            | Instruction::Dcmpg
             | Instruction::Ireturn
+            | Instruction::Lreturn
+            | Instruction::Freturn
+            | Instruction::Dreturn
             | Instruction::Areturn
             | Instruction::Iaload
             | Instruction::Laload
+            | Instruction::Faload
+            | Instruction::Daload
             | Instruction::Aaload
             | Instruction::Baload
+            | Instruction::Caload
+            | Instruction::Saload
             | Instruction::Iastore
             | Instruction::Lastore
+            | Instruction::Fastore
+            | Instruction::Dastore
             | Instruction::Aastore
             | Instruction::Bastore
+            | Instruction::Castore
+            | Instruction::Sastore
+            | Instruction::Arraylength
+            | Instruction::Athrow
             | Instruction::Return => 0,
+            // the operands of wide are longer than the original instruction. they are skipped one by one
+            Instruction::Noope => 0,
             instruction => unimplemented!("{}", instruction),
         }
     }
 }
+
+// the offset is signed 32-bit and relative to the address of the switch
+fn to_jump_pointer(code_length: usize, offset: usize) -> usize {
+    (code_length as i64 + offset as u32 as i32 as i64 - 1) as usize
+}
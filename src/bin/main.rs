@@ -4,6 +4,11 @@ use clap::{App, Arg};
 use r_jvm;
 
 fn main() {
+    // -Xmx16m is the same as --max-heap-size=16m
+    let args = std::env::args().map(|arg| match arg.strip_prefix("-Xmx") {
+        Some(size) => format!("--max-heap-size={}", size),
+        None => arg,
+    });
     let matches = App::new("rj")
         .version("0.1")
         .author("rchaser53 <tayoshizawa29@gmail.com>")
@@ -14,14 +19,39 @@ fn main() {
                 .long("debug")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("max-stack-depth")
+                .help("the max number of the frames. StackOverflowError is thrown over it")
+                .long("max-stack-depth")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("max-heap-size")
+                .help("the max heap size. e.g. 64m. OutOfMemoryError is thrown over it")
+                .long("max-heap-size")
+                .takes_value(true),
+        )
         .args_from_usage(
             "
             <INPUT>              'Sets the input file to use'",
         )
-        .get_matches();
+        .get_matches_from(args);
+
+    if let Some(max_stack_depth) = matches
+        .value_of("max-stack-depth")
+        .and_then(|value| value.parse::<usize>().ok())
+    {
+        r_jvm::set_max_stack_depth(max_stack_depth);
+    }
+    if let Some(max_heap_size) = matches
+        .value_of("max-heap-size")
+        .and_then(r_jvm::parse_memory_size)
+    {
+        r_jvm::set_max_heap_size(max_heap_size);
+    }
 
     if let Some(file_name) = matches.value_of("INPUT") {
-        r_jvm::execute(
+        let is_succeeded = r_jvm::execute(
             file_name.to_string(),
             matches
                 .value_of("debug")
@@ -29,6 +59,10 @@ fn main() {
                 .parse::<usize>()
                 .unwrap_or(0),
         );
+        // same as java. the uncaught exception exits with 1
+        if !is_succeeded {
+            std::process::exit(1);
+        }
     } else {
         println!("should input the file");
     }
@@ -29,6 +29,14 @@ impl ConstantPool {
                         ConstantMethodref::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantMethodref(item), update_index)
                 }
+                ConstPoolTag::ConstantInterfaceMethodref => {
+                    let (item, update_index) =
+                        ConstantInterfaceMethodref::create_and_update_index(inputs, update_index);
+                    (
+                        ConstPoolItem::ConstantInterfaceMethodref(item),
+                        update_index,
+                    )
+                }
                 ConstPoolTag::ConstantNameAndType => {
                     let (item, update_index) =
                         ConstantNameAndType::create_and_update_index(inputs, update_index);
@@ -49,11 +57,31 @@ impl ConstantPool {
                         ConstantFieldref::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantFieldref(item), update_index)
                 }
+                ConstPoolTag::ConstantInteger => {
+                    let (item, update_index) =
+                        ConstantInteger::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantInteger(item), update_index)
+                }
                 ConstPoolTag::ConstantFloat => {
                     let (item, update_index) =
                         ConstantFloat::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantFloat(item), update_index)
                 }
+                ConstPoolTag::ConstantMethodHandle => {
+                    let (item, update_index) =
+                        ConstantMethodHandle::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantMethodHandle(item), update_index)
+                }
+                ConstPoolTag::ConstantMethodType => {
+                    let (item, update_index) =
+                        ConstantMethodType::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantMethodType(item), update_index)
+                }
+                ConstPoolTag::ConstantInvokeDynamic => {
+                    let (item, update_index) =
+                        ConstantInvokeDynamic::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantInvokeDynamic(item), update_index)
+                }
                 ConstPoolTag::ConstantLong => {
                     let (item, update_index) =
                         ConstantLong::create_and_update_index(inputs, update_index);
@@ -124,8 +152,12 @@ next tag: {}",
                 ConstPoolItem::ConstantFieldref(_) => stack.push(Item::Fieldref(index)),
                 ConstPoolItem::ConstantUtf8(item) => stack.push(Item::String(item.id)),
                 ConstPoolItem::ConstantLong(ref item) => {
-                    stack.push(Item::Long(item.high_bytes));
-                    stack.push(Item::Long(item.low_bytes));
+                    stack.push(Item::Long(item.value()));
+                    stack.push(Item::Long(item.value()));
+                }
+                ConstPoolItem::ConstantDouble(ref item) => {
+                    stack.push(Item::Double(item.value()));
+                    stack.push(Item::Double(item.value()));
                 }
                 ConstPoolItem::ConstantNull => {
                     unreachable!("index: {}. should not come ConstantNull", index)
@@ -179,6 +211,60 @@ next tag: {}",
         }
     }
 
+    pub fn get_interface_method_ref(&self, index: usize) -> &ConstantInterfaceMethodref {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInterfaceMethodref(ref item)) => item,
+            _ => unreachable!(
+                "should be ConstantInterfaceMethodref. actual {:?}",
+                self.0.get(index)
+            ),
+        }
+    }
+
+    // invokestatic and invokespecial can refer both Methodref and InterfaceMethodref
+    // (class_index, name_and_type_index)
+    pub fn get_method_ref_indexes(&self, index: usize) -> (usize, usize) {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInterfaceMethodref(ref item)) => {
+                (item.class_index, item.name_and_type_index)
+            }
+            _ => {
+                let item = self.get_method_ref(index);
+                (item.class_index, item.name_and_type_index)
+            }
+        }
+    }
+
+    pub fn get_method_handle(&self, index: usize) -> &ConstantMethodHandle {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantMethodHandle(ref item)) => item,
+            _ => unreachable!(
+                "should be ConstantMethodHandle. actual {:?}",
+                self.0.get(index)
+            ),
+        }
+    }
+
+    pub fn get_method_type(&self, index: usize) -> &ConstantMethodType {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantMethodType(ref item)) => item,
+            _ => unreachable!(
+                "should be ConstantMethodType. actual {:?}",
+                self.0.get(index)
+            ),
+        }
+    }
+
+    pub fn get_invoke_dynamic(&self, index: usize) -> &ConstantInvokeDynamic {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInvokeDynamic(ref item)) => item,
+            _ => unreachable!(
+                "should be ConstantInvokeDynamic. actual {:?}",
+                self.0.get(index)
+            ),
+        }
+    }
+
     pub fn get_field_ref(&self, index: usize) -> &ConstantFieldref {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantFieldref(ref item)) => item,
@@ -193,27 +279,48 @@ next tag: {}",
         }
     }
 
+    pub fn get_integer(&self, index: usize) -> i32 {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInteger(item)) => item.value(),
+            _ => unreachable!("should be ConstantInteger. actual {:?}", self.0.get(index)),
+        }
+    }
+
     pub fn get_float(&self, index: usize) -> f32 {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantFloat(ConstantFloat { bytes, .. })) => {
-                let s: f32 = if bytes >> 31 == 0 as usize { 1.0 } else { -1.0 };
-                let e = ((bytes >> 23 as usize) & 0xff) as f32;
-                let m = if e == 0.0 {
-                    ((bytes & 0x7fffff) << 1) as f32
-                } else {
-                    ((bytes & 0x7fffff) | 0x800000) as f32
-                };
-                // s * m * 2e-150
-                s * m * f32::powf(2.0f32, e - 150 as f32)
+                f32::from_bits(*bytes as u32)
             }
             _ => unreachable!("should be ConstantFloat. actual {:?}", self.0.get(index)),
         }
     }
 
+    // the value of ConstantValue attribute. int, short, char, byte and boolean use ConstantInteger
+    pub fn get_constant_value(&self, index: usize) -> (Item, Item) {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInteger(item)) => (Item::Int(item.value()), Item::Null),
+            Some(ConstPoolItem::ConstantFloat(_)) => {
+                (Item::Float(self.get_float(index)), Item::Null)
+            }
+            Some(ConstPoolItem::ConstantLong(item)) => {
+                (Item::Long(item.value()), Item::Long(item.value()))
+            }
+            Some(ConstPoolItem::ConstantDouble(item)) => {
+                (Item::Double(item.value()), Item::Double(item.value()))
+            }
+            Some(ConstPoolItem::ConstantString(_)) => {
+                (Item::String(self.get_string(index)), Item::Null)
+            }
+            _ => unreachable!("should be constant value. actual {:?}", self.0.get(index)),
+        }
+    }
+
     pub fn get_item_tag(&self, index: usize) -> ConstPoolTag {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantString(_)) => ConstPoolTag::ConstantString,
+            Some(ConstPoolItem::ConstantInteger(_)) => ConstPoolTag::ConstantInteger,
             Some(ConstPoolItem::ConstantFloat(_)) => ConstPoolTag::ConstantFloat,
+            Some(ConstPoolItem::ConstantClass(_)) => ConstPoolTag::ConstantClass,
             _ => unimplemented!(),
         }
     }
@@ -253,6 +360,10 @@ impl fmt::Display for ConstantPool {
                     "  #{} = Methodref        #{}.#{}",
                     index, item.class_index, item.name_and_type_index,
                 ),
+                ConstPoolItem::ConstantInterfaceMethodref(item) => format!(
+                    "  #{} = InterfaceMethodref #{}.#{}",
+                    index, item.class_index, item.name_and_type_index,
+                ),
                 ConstPoolItem::ConstantFieldref(item) => format!(
                     "  #{} = Fieldref         #{}.#{}",
                     index, item.class_index, item.name_and_type_index
@@ -272,16 +383,26 @@ impl fmt::Display for ConstantPool {
                     "  #{} = NameAndType      #{}:#{}",
                     index, item.name_index, item.descriptor_index
                 ),
-                ConstPoolItem::ConstantLong(item) => format!(
-                    "  #{} = Long             {}l",
-                    index,
-                    ((item.high_bytes as i64) << 32) as i64 | item.low_bytes as i64
+                ConstPoolItem::ConstantMethodHandle(item) => format!(
+                    "  #{} = MethodHandle     {}:#{}",
+                    index, item.reference_kind, item.reference_index
                 ),
-                ConstPoolItem::ConstantDouble(item) => format!(
-                    "  #{} = Double           {}",
-                    index,
-                    ((item.high_bytes << 8) | item.low_bytes) & 0xFFFF
+                ConstPoolItem::ConstantMethodType(item) => {
+                    format!("  #{} = MethodType       #{}", index, item.descriptor_index)
+                }
+                ConstPoolItem::ConstantInvokeDynamic(item) => format!(
+                    "  #{} = InvokeDynamic    #{}:#{}",
+                    index, item.bootstrap_method_attr_index, item.name_and_type_index
                 ),
+                ConstPoolItem::ConstantInteger(item) => {
+                    format!("  #{} = Integer          {}", index, item.value())
+                }
+                ConstPoolItem::ConstantLong(item) => {
+                    format!("  #{} = Long             {}l", index, item.value())
+                }
+                ConstPoolItem::ConstantDouble(item) => {
+                    format!("  #{} = Double           {}d", index, item.value())
+                }
                 _ => unimplemented!(),
             };
             result.push(rw);
@@ -338,17 +459,17 @@ pub enum ConstPoolItem {
     ConstantClass(ConstantClass),
     ConstantFieldref(ConstantFieldref),
     ConstantMethodref(ConstantMethodref),
-    ConstantInterfaceMethodref,
+    ConstantInterfaceMethodref(ConstantInterfaceMethodref),
     ConstantString(ConstantString),
-    ConstantInteger,
+    ConstantInteger(ConstantInteger),
     ConstantFloat(ConstantFloat),
     ConstantLong(ConstantLong),
     ConstantDouble(ConstantDouble),
     ConstantNameAndType(ConstantNameAndType),
     ConstantUtf8(ConstantUtf8),
-    ConstantMethodHandle,
-    ConstantMethodType,
-    ConstantInvokeDynamic,
+    ConstantMethodHandle(ConstantMethodHandle),
+    ConstantMethodType(ConstantMethodType),
+    ConstantInvokeDynamic(ConstantInvokeDynamic),
 }
 
 #[derive(Debug, PartialEq)]
@@ -364,13 +485,17 @@ impl ConstantLong {
         let (low_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         (
             ConstantLong {
-                tag: ConstPoolTag::ConstantString,
+                tag: ConstPoolTag::ConstantLong,
                 high_bytes,
                 low_bytes,
             },
             index,
         )
     }
+
+    pub fn value(&self) -> i64 {
+        ((self.high_bytes as u64) << 32 | self.low_bytes as u64) as i64
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -386,13 +511,17 @@ impl ConstantDouble {
         let (low_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         (
             ConstantDouble {
-                tag: ConstPoolTag::ConstantString,
+                tag: ConstPoolTag::ConstantDouble,
                 high_bytes,
                 low_bytes,
             },
             index,
         )
     }
+
+    pub fn value(&self) -> f64 {
+        f64::from_bits((self.high_bytes as u64) << 32 | self.low_bytes as u64)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -414,6 +543,29 @@ impl ConstantString {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ConstantInteger {
+    pub tag: ConstPoolTag,
+    pub bytes: usize, // u4
+}
+
+impl ConstantInteger {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantInteger, usize) {
+        let (bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
+        (
+            ConstantInteger {
+                tag: ConstPoolTag::ConstantInteger,
+                bytes,
+            },
+            index,
+        )
+    }
+
+    pub fn value(&self) -> i32 {
+        self.bytes as u32 as i32
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ConstantFloat {
     pub tag: ConstPoolTag,
@@ -520,6 +672,98 @@ impl ConstantMethodref {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ConstantInterfaceMethodref {
+    pub tag: ConstPoolTag,
+    pub class_index: usize,         // u2
+    pub name_and_type_index: usize, // u2
+}
+
+impl ConstantInterfaceMethodref {
+    pub fn create_and_update_index(
+        inputs: &[u8],
+        index: usize,
+    ) -> (ConstantInterfaceMethodref, usize) {
+        let (class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_and_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        (
+            ConstantInterfaceMethodref {
+                tag: ConstPoolTag::ConstantInterfaceMethodref,
+                class_index,
+                name_and_type_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantMethodHandle {
+    pub tag: ConstPoolTag,
+    pub reference_kind: usize,  // u1
+    pub reference_index: usize, // u2
+}
+
+impl ConstantMethodHandle {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantMethodHandle, usize) {
+        let (reference_kind, index) = extract_x_byte_as_usize(inputs, index, 1);
+        let (reference_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        (
+            ConstantMethodHandle {
+                tag: ConstPoolTag::ConstantMethodHandle,
+                reference_kind,
+                reference_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantMethodType {
+    pub tag: ConstPoolTag,
+    pub descriptor_index: usize, // u2
+}
+
+impl ConstantMethodType {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantMethodType, usize) {
+        let (descriptor_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        (
+            ConstantMethodType {
+                tag: ConstPoolTag::ConstantMethodType,
+                descriptor_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantInvokeDynamic {
+    pub tag: ConstPoolTag,
+    pub bootstrap_method_attr_index: usize, // u2
+    pub name_and_type_index: usize,         // u2
+}
+
+impl ConstantInvokeDynamic {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantInvokeDynamic, usize) {
+        let (bootstrap_method_attr_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_and_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        (
+            ConstantInvokeDynamic {
+                tag: ConstPoolTag::ConstantInvokeDynamic,
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            index,
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ConstantUtf8 {
     pub id: usize, // custom value
@@ -580,6 +824,113 @@ mod test {
         );
     }
 
+    #[test]
+    fn constant_pool_constant_interface_methodref() {
+        let mut inputs = vec![
+            0x0b, // interface_methodref
+            0x00, 0x0c, // class_index
+            0x00, 0x0d, // name_and_type_index
+        ];
+
+        let result = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 2);
+
+        assert_eq!(
+            result,
+            (
+                ConstantPool(vec![
+                    ConstPoolItem::ConstantNull,
+                    ConstPoolItem::ConstantInterfaceMethodref(ConstantInterfaceMethodref {
+                        tag: ConstPoolTag::ConstantInterfaceMethodref,
+                        class_index: 0x0c,
+                        name_and_type_index: 0x0d
+                    })
+                ]),
+                inputs.len()
+            )
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_invoke_dynamic() {
+        let mut inputs = vec![
+            0x0f, // method_handle
+            0x06, // reference_kind
+            0x00, 0x03, // reference_index
+            0x10, // method_type
+            0x00, 0x04, // descriptor_index
+            0x12, // invoke_dynamic
+            0x00, 0x00, // bootstrap_method_attr_index
+            0x00, 0x05, // name_and_type_index
+        ];
+
+        let result = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 4);
+
+        assert_eq!(
+            result,
+            (
+                ConstantPool(vec![
+                    ConstPoolItem::ConstantNull,
+                    ConstPoolItem::ConstantMethodHandle(ConstantMethodHandle {
+                        tag: ConstPoolTag::ConstantMethodHandle,
+                        reference_kind: 0x06,
+                        reference_index: 0x03
+                    }),
+                    ConstPoolItem::ConstantMethodType(ConstantMethodType {
+                        tag: ConstPoolTag::ConstantMethodType,
+                        descriptor_index: 0x04
+                    }),
+                    ConstPoolItem::ConstantInvokeDynamic(ConstantInvokeDynamic {
+                        tag: ConstPoolTag::ConstantInvokeDynamic,
+                        bootstrap_method_attr_index: 0x00,
+                        name_and_type_index: 0x05
+                    })
+                ]),
+                inputs.len()
+            )
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_long_and_double() {
+        let mut inputs = vec![
+            0x05, // long
+            0xff, 0xff, 0xff, 0xff, // high_bytes
+            0xff, 0xff, 0xff, 0xfe, // low_bytes
+            0x06, // double
+            0xc0, 0x04, 0x00, 0x00, // high_bytes
+            0x00, 0x00, 0x00, 0x00, // low_bytes
+        ];
+
+        let (constant_pool, _) = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 5);
+        let mut stack = vec![];
+        constant_pool.create_and_set_operand_stack_item(&mut stack, 1);
+        constant_pool.create_and_set_operand_stack_item(&mut stack, 3);
+
+        assert_eq!(
+            stack,
+            vec![
+                Item::Long(-2),
+                Item::Long(-2),
+                Item::Double(-2.5),
+                Item::Double(-2.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_integer_and_float() {
+        let mut inputs = vec![
+            0x03, // integer
+            0x80, 0x00, 0x00, 0x00, // bytes
+            0x04, // float
+            0xbf, 0xc0, 0x00, 0x00, // bytes
+        ];
+
+        let (constant_pool, _) = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 3);
+        assert_eq!(constant_pool.get_integer(1), i32::MIN);
+        assert_eq!(constant_pool.get_float(2), -1.5);
+    }
+
     #[test]
     fn constant_pool_constant_class() {
         let mut inputs = vec![
@@ -1,22 +1,42 @@
-use crate::array::{Array, ArrayMap};
+use crate::array::{
+    get_array_size, get_primitive_element_size, Array, ArrayMap, ARRAY_ELEMENT_SIZE,
+    CUSTOM_ELEMENT_SIZE,
+};
 use crate::attribute::code::Code;
 use crate::attribute::instruction::Instruction;
-use crate::constant::{ConstPoolTag, ConstantNameAndType, ConstantPool};
-use crate::field::{BaseType, FieldDescriptor};
-use crate::java_class::{custom::Custom, JavaClass};
-use crate::object::{ObjectMap, Objectref};
+use crate::constant::{ConstPoolTag, ConstantPool};
+use crate::field::{
+    get_parameter_descriptors, get_parameter_slot_count, get_return_descriptor, get_slot_count,
+    BaseType, FieldDescriptor,
+};
+use crate::gc::GarbageCollector;
+use crate::java_class::{
+    builtin::{
+        describe_throwable, get_cause, get_enum_field, BuitlInCodeType, ENUM_NAME_SLOT,
+        THROWABLE_CAUSE_SLOT, THROWABLE_DETAIL_MESSAGE_SLOT,
+    },
+    custom::Custom,
+    layout::FieldLayout,
+    resolution::ResolvedMethod,
+    ClassInitState, JavaClass,
+};
+use crate::object::{get_object_size, Lambda, ObjectMap, Objectref};
 use crate::operand::Item;
+use crate::option::RJ_OPTION;
 
 use crate::stackframe::Stackframe;
 use crate::string_pool::StringPool;
-use crate::utils::{emit_debug_info, iniailize_primitive_array, initialize_objectref_array};
-use crate::wasm::get_file_content;
+use crate::utils::{
+    emit_debug_info, format_floating_point, get_primitive_array_descriptor,
+    get_primitive_array_type, iniailize_primitive_array,
+};
+use crate::wasm::{get_file_content, print_error};
 
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::mem;
 use std::path::Path;
+use std::rc::Rc;
 
 #[derive(Debug)]
 pub struct Context<'a> {
@@ -27,74 +47,725 @@ pub struct Context<'a> {
     pub static_fields: StaticFields,
     pub object_map: ObjectMap,
     pub array_map: ArrayMap,
+    // object id of the exception which is being thrown
+    pub thrown_exception: Option<usize>,
+    // object id => lambda which is created by invokedynamic
+    pub lambda_map: HashMap<usize, Lambda>,
+    // class_name => initialization state
+    pub class_init_states: HashMap<usize, ClassInitState>,
+    pub max_stack_depth: usize,
+    // class_name => layout which is computed when the class is linked
+    pub field_layouts: HashMap<usize, Rc<FieldLayout>>,
+    pub gc: GarbageCollector,
 }
 
 pub type ClassMap = HashMap<usize, JavaClass>;
-// class_name, field_name
-pub type StaticFields = HashMap<(usize, usize), (Item, Item)>;
+// indexed by the static slot of FieldLayout
+pub type StaticFields = Vec<(Item, Item)>;
 
 impl<'a> Context<'a> {
-    pub fn new(
-        string_map: &mut StringPool,
-        class_map: ClassMap,
-        class_file: &Custom,
-        root_path: &'a str,
-    ) -> Context<'a> {
-        let mut static_fields = setup_static_fields(string_map, &class_map);
-        set_static_fields(string_map, &class_file, &mut static_fields);
-
+    pub fn new(class_map: ClassMap, root_path: &'a str) -> Context<'a> {
+        let option = RJ_OPTION.lock().unwrap();
         Context {
             class_map,
             program_count: 0,
             stack_frames: vec![],
             root_path,
-            static_fields,
+            static_fields: vec![],
             object_map: ObjectMap::new(),
             array_map: ArrayMap::new(),
+            thrown_exception: None,
+            lambda_map: HashMap::new(),
+            class_init_states: HashMap::new(),
+            max_stack_depth: option.max_stack_depth,
+            field_layouts: HashMap::new(),
+            gc: GarbageCollector::new(option.max_heap_size),
         }
     }
 
-    pub fn run_entry_file(&mut self, string_map: &mut StringPool, class_file: Custom) {
-        let entry_method = class_file
-            .get_entry_method()
-            .expect("add handler in the case failed to find entry method");
-
-        // TBD Perhaps this method is not invoked from super_class
-        let super_class_index = class_file.super_class;
-        let super_class_ref = class_file.cp_info.get_class_ref(super_class_index);
-        let super_class_name = class_file.cp_info.get_utf8(super_class_ref.name_index);
-        let stack_frame_item_0 = Item::Classref(super_class_name);
+    // returns false if the exception is not caught
+    pub fn run_entry_file(&mut self, string_map: &mut StringPool, class_file: Custom) -> bool {
+        // the entry class is initialized before main is invoked
+        let this_class_name = class_file.this_class_name();
+        let class_file = Rc::new(class_file);
+        let class = JavaClass::Custom(class_file.clone());
+        self.class_map.insert(this_class_name, class.clone());
+        self.link_class(string_map, &class);
+        if !self.initialize_class(string_map, this_class_name) {
+            self.run_frames(string_map);
+        }
 
-        if let Some(code) = class_file.get_clinit_code() {
-            let stack_frame = Stackframe::new(code.max_locals as usize);
+        if self.thrown_exception.is_none() {
+            let method_index = class_file
+                .get_entry_method_index()
+                .expect("add handler in the case failed to find entry method");
+            let entry_method = &class_file.methods[method_index];
+
+            // TBD Perhaps this method is not invoked from super_class
+            let super_class_index = class_file.super_class;
+            let super_class_ref = class_file.cp_info.get_class_ref(super_class_index);
+            let super_class_name = class_file.cp_info.get_utf8(super_class_ref.name_index);
+            let stack_frame_item_0 = Item::Classref(super_class_name);
+
+            let code = entry_method
+                .extract_code()
+                .expect("should exist code in method");
+            let mut stack_frame =
+                Stackframe::new(code.max_locals as usize, code.max_stack as usize);
+            stack_frame.local_variables[0] = stack_frame_item_0;
+            stack_frame.method = Some((class_file.clone(), method_index));
             self.stack_frames.push(stack_frame);
-            self.call_custom_class_method(string_map, &class_file, code);
+            self.run_frames(string_map);
         }
 
-        let code = entry_method
-            .extract_code()
-            .expect("should exist code in method");
-        let mut stack_frame = Stackframe::new(code.max_locals as usize);
-        stack_frame.local_variables.push(stack_frame_item_0);
-        self.stack_frames.push(stack_frame);
-        self.run_method(string_map, &class_file, code);
+        if let Some(object_id) = self.thrown_exception.take() {
+            let description = describe_throwable(string_map, &self.object_map, object_id);
+            print_error(&format!("Exception in thread \"main\" {}", description));
+            let mut cause = get_cause(&self.object_map, object_id);
+            while let Item::Objectref(cause_id) = cause {
+                let description = describe_throwable(string_map, &self.object_map, cause_id);
+                print_error(&format!("Caused by: {}", description));
+                cause = get_cause(&self.object_map, cause_id);
+            }
+            return false;
+        }
+        true
+    }
 
-        self.class_map
-            .insert(class_file.this_class_name(), JavaClass::Custom(class_file));
+    // the invoked methods are pushed on the frames instead of the Rust stack.
+    // e.g. <clinit> and toString for the string concatenation
+    fn run_frames(&mut self, string_map: &mut StringPool) {
+        while !self.stack_frames.is_empty() {
+            let (class_file, method_index) = self
+                .get_last_stackframe()
+                .method
+                .clone()
+                .expect("should be the frame of the custom method");
+            let code = class_file.methods[method_index]
+                .extract_code()
+                .expect("should exist code in method");
+            self.run_frame(string_map, &class_file, code);
+        }
     }
 
-    fn run_method(&mut self, string_map: &mut StringPool, class_file: &Custom, code: &Code) {
-        let mut index = 0;
+    // returns when the frame invokes a method or the frame is discarded
+    fn run_frame(&mut self, string_map: &mut StringPool, class_file: &Custom, code: &Code) {
+        let depth = self.stack_frames.len();
+        let mut index = self.get_last_stackframe().return_pc;
+        if self.thrown_exception.is_some() {
+            // the exception is thrown by the invoked method
+            let pc = self.get_last_stackframe().pc;
+            match self.find_exception_handler(string_map, class_file, code, pc) {
+                Some(handler_pc) => index = handler_pc,
+                None => {
+                    self.pop_frame(string_map);
+                    return;
+                }
+            }
+        }
+
         while let Some(instruction) = code.code.get(index) {
+            // every item is on the frames between the instructions
+            if self.gc.should_collect(&self.object_map, &self.array_map) {
+                self.collect_garbage();
+            }
             emit_debug_info(instruction, self.stack_frames.last());
             let (should_finish, update_index) =
                 self.execute(string_map, class_file, instruction, index);
+            if self.thrown_exception.is_some() {
+                // the stack_frame is discarded and the exception goes to the caller
+                // if this method doesn't have the handler
+                if let Some(handler_pc) =
+                    self.find_exception_handler(string_map, class_file, code, index)
+                {
+                    index = handler_pc;
+                    continue;
+                }
+                break;
+            }
             if should_finish {
                 break;
             }
+            if self.stack_frames.len() > depth {
+                let return_pc = if self.get_last_stackframe().retries_caller_instruction() {
+                    index
+                } else {
+                    update_index + 1
+                };
+                let stackframe = &mut self.stack_frames[depth - 1];
+                stackframe.pc = index;
+                stackframe.return_pc = return_pc;
+                return;
+            }
             index = update_index + 1;
         }
-        self.stack_frames.pop();
+        self.pop_frame(string_map);
+    }
+
+    // the class is initialized when the frame of <clinit> is popped
+    fn pop_frame(&mut self, string_map: &mut StringPool) {
+        let stackframe = self.stack_frames.pop().expect("should exist stack_frame");
+        let (mut class_name, class_file) = match (stackframe.initializing_class, stackframe.method)
+        {
+            (Some(class_name), Some((class_file, _))) => (class_name, class_file),
+            _ => return,
+        };
+        if self.thrown_exception.is_none() {
+            self.class_init_states
+                .insert(class_name, ClassInitState::Initialized);
+            return;
+        }
+        self.wrap_initializer_exception(string_map, &class_file);
+        self.class_init_states
+            .insert(class_name, ClassInitState::Erroneous);
+
+        // <clinit> of the subclass hasn't run yet if it waits for the super class.
+        // it fails with the same exception
+        while let Some((subclass_name, subclass_file)) =
+            self.stack_frames.last().and_then(|stackframe| {
+                match (stackframe.initializing_class, &stackframe.method) {
+                    (Some(subclass_name), Some((subclass_file, _))) => {
+                        Some((subclass_name, subclass_file.clone()))
+                    }
+                    _ => None,
+                }
+            })
+        {
+            if !self.is_subclass_of(string_map, &subclass_file, subclass_name, class_name) {
+                break;
+            }
+            self.stack_frames.pop();
+            self.class_init_states
+                .insert(subclass_name, ClassInitState::Erroneous);
+            class_name = subclass_name;
+        }
+    }
+
+    fn find_exception_handler(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        code: &Code,
+        index: usize,
+    ) -> Option<usize> {
+        let object_id = self
+            .thrown_exception
+            .expect("should exist thrown exception");
+        let exception_class_name = self
+            .object_map
+            .get(&object_id)
+            .expect("should exist exception in object_map")
+            .class_name_id;
+
+        for item in code.exception_table.iter() {
+            if !item.is_in_range(index) {
+                continue;
+            }
+            let is_matched = item.catch_type == 0 || {
+                let catch_class_name = class_file
+                    .cp_info
+                    .get_class_ref_name(item.catch_type as usize);
+                self.is_subclass_of(
+                    string_map,
+                    class_file,
+                    exception_class_name,
+                    catch_class_name,
+                )
+            };
+
+            if is_matched {
+                self.thrown_exception = None;
+                let operand_stack = self.get_operand_stack();
+                operand_stack.clear();
+                operand_stack.push(Item::Objectref(object_id));
+                return Some(item.handler_pc as usize);
+            }
+        }
+        None
+    }
+
+    // the super classes and the interfaces are searched
+    fn is_subclass_of(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        target_class_name: usize,
+    ) -> bool {
+        let mut class_names = vec![class_name];
+        let mut index = 0;
+        while let Some(class_name) = class_names.get(index).cloned() {
+            if class_name == target_class_name {
+                return true;
+            }
+            if let Some(super_class_name) =
+                self.get_super_class_name(string_map, class_file, class_name)
+            {
+                class_names.push(super_class_name);
+            }
+            class_names.append(&mut self.get_interface_names(string_map, class_file, class_name));
+            index += 1;
+        }
+        false
+    }
+
+    // for checkcast and instanceof. arrays are covariant. e.g. String[] is Object[]
+    fn is_assignable(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        target_class_name: usize,
+    ) -> bool {
+        if class_name == target_class_name {
+            return true;
+        }
+        let class_name_value = string_map.get_value(&class_name);
+        let target_class_name_value = string_map.get_value(&target_class_name);
+        if target_class_name_value == "java/lang/Object" {
+            return true;
+        }
+
+        match (
+            class_name_value.strip_prefix('['),
+            target_class_name_value.strip_prefix('['),
+        ) {
+            (Some(component), Some(target_component)) => match (
+                to_component_class_name(component),
+                to_component_class_name(target_component),
+            ) {
+                (Some(component), Some(target_component)) => {
+                    let component = string_map.insert(component);
+                    let target_component = string_map.insert(target_component);
+                    self.is_assignable(string_map, class_file, component, target_component)
+                }
+                // primitive arrays are assignable only to the same type
+                _ => false,
+            },
+            (Some(_), None) => {
+                target_class_name_value == "java/lang/Cloneable"
+                    || target_class_name_value == "java/io/Serializable"
+            }
+            (None, Some(_)) => false,
+            (None, None) => {
+                self.is_subclass_of(string_map, class_file, class_name, target_class_name)
+            }
+        }
+    }
+
+    // None for null
+    // arrays are covariant. so the item is checked with the component type of the array
+    fn is_storable(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        array_id: usize,
+        item: &Item,
+    ) -> bool {
+        let class_name = match self.get_item_class_name(string_map, item) {
+            Some(class_name) => class_name,
+            None => return true,
+        };
+        let array_class_name = self
+            .array_map
+            .get_class_name(&array_id)
+            .expect("should exist class name of array");
+        let component = to_component_class_name(&string_map.get_value(&array_class_name)[1..])
+            .expect("should be the reference array");
+        let component = string_map.insert(component);
+        self.is_assignable(string_map, class_file, class_name, component)
+    }
+
+    fn get_item_class_name(&self, string_map: &mut StringPool, item: &Item) -> Option<usize> {
+        match item {
+            Item::Null => None,
+            Item::Objectref(object_id) => self
+                .object_map
+                .get(object_id)
+                .map(|object_ref| object_ref.class_name_id),
+            Item::Arrayref(array_id) => self.array_map.get_class_name(array_id),
+            Item::String(_) => Some(string_map.insert(String::from("java/lang/String"))),
+            item => unreachable!("{} is not a reference", item),
+        }
+    }
+
+    fn get_super_class_name(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+    ) -> Option<usize> {
+        if let Some(JavaClass::BuiltIn(builtin)) = self.class_map.get(&class_name) {
+            return builtin.super_class_name;
+        }
+        self.with_custom_class(string_map, class_file, class_name, |custom| {
+            custom.super_class_name()
+        })
+        .unwrap_or(None)
+    }
+
+    // returns None if the class is BuiltIn
+    fn with_custom_class<T, F>(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        callback: F,
+    ) -> Option<T>
+    where
+        F: FnOnce(&Custom) -> T,
+    {
+        if class_name == class_file.this_class_name() {
+            return Some(callback(class_file));
+        }
+        match self.load_class(string_map, class_name) {
+            JavaClass::BuiltIn(_) => None,
+            JavaClass::Custom(custom) => Some(callback(&custom)),
+        }
+    }
+
+    // returns None if IncompatibleClassChangeError is thrown
+    fn resolve_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Option<usize> {
+        let class_names = self.find_method_classes(
+            string_map,
+            class_file,
+            class_name,
+            method_name,
+            method_descriptor,
+        );
+        match class_names.as_slice() {
+            [class_name] => Some(*class_name),
+            [] => panic!(
+                "{}.{}{} is not found",
+                string_map.get_value(&class_name),
+                string_map.get_value(&method_name),
+                string_map.get_value(&method_descriptor)
+            ),
+            _ => {
+                let method_name = string_map.get_value(&method_name);
+                let methods: Vec<String> = class_names
+                    .iter()
+                    .map(|class_name| {
+                        format!(
+                            "{}.{}",
+                            string_map.get_value(class_name).replace("/", "."),
+                            method_name
+                        )
+                    })
+                    .collect();
+                self.throw_exception(
+                    string_map,
+                    "java/lang/IncompatibleClassChangeError",
+                    Some(format!(
+                        "Conflicting default methods: {}",
+                        methods.join(" ")
+                    )),
+                );
+                None
+            }
+        }
+    }
+
+    // returns the class name which declares the method. None if the method is not found or
+    // the default methods conflict
+    fn find_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Option<usize> {
+        match self
+            .find_method_classes(
+                string_map,
+                class_file,
+                class_name,
+                method_name,
+                method_descriptor,
+            )
+            .as_slice()
+        {
+            [class_name] => Some(*class_name),
+            _ => None,
+        }
+    }
+
+    // super classes are searched first, and then the maximally-specific default methods of
+    // the interfaces. JVMS 5.4.3.3. the default methods conflict if over one class is returned
+    fn find_method_classes(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Vec<usize> {
+        let mut interface_names = vec![];
+        let mut current_class_name = Some(class_name);
+        while let Some(target_class_name) = current_class_name {
+            if self.has_method_code(
+                string_map,
+                class_file,
+                target_class_name,
+                method_name,
+                method_descriptor,
+            ) {
+                return vec![target_class_name];
+            }
+            interface_names.append(&mut self.get_interface_names(
+                string_map,
+                class_file,
+                target_class_name,
+            ));
+            current_class_name =
+                self.get_super_class_name(string_map, class_file, target_class_name);
+        }
+
+        let mut candidates = vec![];
+        let mut index = 0;
+        while let Some(interface_name) = interface_names.get(index).cloned() {
+            index += 1;
+            if interface_names[..index - 1].contains(&interface_name) {
+                continue;
+            }
+            if self.has_method_code(
+                string_map,
+                class_file,
+                interface_name,
+                method_name,
+                method_descriptor,
+            ) {
+                candidates.push(interface_name);
+            }
+            interface_names.append(&mut self.get_interface_names(
+                string_map,
+                class_file,
+                interface_name,
+            ));
+        }
+
+        // the default method is overridden by the one of the subinterface
+        let mut class_names = vec![];
+        for candidate in candidates.iter() {
+            let is_overridden = candidates.iter().any(|other| {
+                other != candidate
+                    && self.is_subclass_of(string_map, class_file, *other, *candidate)
+            });
+            if !is_overridden {
+                class_names.push(*candidate);
+            }
+        }
+        class_names
+    }
+
+    // abstract methods don't have code
+    fn has_method_code(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> bool {
+        match self.class_map.get(&class_name) {
+            Some(JavaClass::BuiltIn(builtin)) => builtin.methods.contains_key(&method_name),
+            _ => self
+                .with_custom_class(string_map, class_file, class_name, |custom| {
+                    custom
+                        .get_method_by_string(method_name, method_descriptor)
+                        .and_then(|method| method.extract_code())
+                        .is_some()
+                })
+                .unwrap_or(false),
+        }
+    }
+
+    fn get_interface_names(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+    ) -> Vec<usize> {
+        if let Some(JavaClass::BuiltIn(builtin)) = self.class_map.get(&class_name) {
+            return builtin.interfaces.clone();
+        }
+        self.with_custom_class(string_map, class_file, class_name, |custom| {
+            custom.get_interface_names()
+        })
+        .unwrap_or(vec![])
+    }
+
+    // returns (class_name which declares the field, slot).
+    // the result is cached in the class which has the constant pool
+    fn resolve_field_ref(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        index: usize,
+        is_static: bool,
+    ) -> (usize, usize) {
+        if let Some(field) = class_file.resolution_cache.get_field(index) {
+            return field;
+        }
+        let (class_name, field_name) = class_file.cp_info.get_class_and_field_name(index);
+        let layout = self.get_field_layout(string_map, class_name);
+        let field = if is_static {
+            layout.get_static_slot(field_name)
+        } else {
+            layout
+                .get_instance_slot(field_name)
+                .map(|slot| (class_name, slot))
+        };
+        let field = match field {
+            Some(field) => field,
+            None => panic!(
+                "{}.{} is not found",
+                string_map.get_value(&class_name),
+                string_map.get_value(&field_name)
+            ),
+        };
+        class_file.resolution_cache.set_field(index, field);
+        field
+    }
+
+    fn resolve_method_ref(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        index: usize,
+    ) -> ResolvedMethod {
+        if let Some(method) = class_file.resolution_cache.get_method(index) {
+            return method;
+        }
+        let (class_name, method_name, method_descriptor) =
+            self.get_related_method_info(class_file, index);
+        let method = ResolvedMethod {
+            class_name,
+            method_name,
+            method_descriptor,
+            parameter_slot_count: get_parameter_slot_count(
+                &string_map.get_value(&method_descriptor),
+            ),
+            is_private: self.is_private_method(
+                string_map,
+                class_file,
+                class_name,
+                method_name,
+                method_descriptor,
+            ),
+            is_array: string_map.get_value(&class_name).starts_with('['),
+        };
+        class_file.resolution_cache.set_method(index, method);
+        method
+    }
+
+    // the arrays and the builtin classes don't have private methods
+    fn is_private_method(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> bool {
+        if string_map.get_value(&class_name).starts_with('[') {
+            return false;
+        }
+        match self.find_method_class(
+            string_map,
+            class_file,
+            class_name,
+            method_name,
+            method_descriptor,
+        ) {
+            Some(class_name) => self
+                .with_custom_class(string_map, class_file, class_name, |custom| {
+                    custom
+                        .get_method_by_string(method_name, method_descriptor)
+                        .map_or(false, |method| method.is_private())
+                })
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
+    // the inline cache of the call site is used while the receiver class is the same.
+    // the class name is always the same for invokestatic and invokespecial.
+    // returns the class name and the method index which is None for the builtin classes.
+    // None if IncompatibleClassChangeError is thrown
+    fn select_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        pc: usize,
+        receiver_class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Option<(usize, Option<usize>)> {
+        let method_index = match self.get_last_stackframe().method {
+            Some((_, method_index)) => method_index,
+            None => unreachable!("should be the frame of the custom method"),
+        };
+        let call_site = (method_index, pc);
+        if let Some(selected_method) = class_file
+            .resolution_cache
+            .get_method_class(call_site, receiver_class_name)
+        {
+            return Some(selected_method);
+        }
+        let class_name = self.resolve_method_class(
+            string_map,
+            class_file,
+            receiver_class_name,
+            method_name,
+            method_descriptor,
+        )?;
+        let method_index = match self.load_class(string_map, class_name) {
+            JavaClass::Custom(custom_class) => {
+                custom_class.get_method_index_by_string(method_name, method_descriptor)
+            }
+            JavaClass::BuiltIn(_) => None,
+        };
+        class_file.resolution_cache.set_method_class(
+            call_site,
+            receiver_class_name,
+            (class_name, method_index),
+        );
+        Some((class_name, method_index))
+    }
+
+    fn get_receiver_class_name(&mut self, parameter_slot_count: usize) -> Option<usize> {
+        let object_id = self.get_receiver_object_id(parameter_slot_count)?;
+        self.object_map
+            .get(&object_id)
+            .map(|object_ref| object_ref.class_name_id)
+    }
+
+    fn get_receiver_object_id(&mut self, parameter_slot_count: usize) -> Option<usize> {
+        let operand_stack = self.get_operand_stack();
+        let receiver_index = operand_stack.len().checked_sub(parameter_slot_count + 1)?;
+        if let Some(Item::Objectref(object_id)) = operand_stack.get(receiver_index) {
+            Some(*object_id)
+        } else {
+            None
+        }
+    }
+
+    fn is_null_receiver(&mut self, parameter_slot_count: usize) -> bool {
+        let operand_stack = self.get_operand_stack();
+        operand_stack
+            .len()
+            .checked_sub(parameter_slot_count + 1)
+            .and_then(|receiver_index| operand_stack.get(receiver_index))
+            == Some(&Item::Null)
     }
 
     pub fn execute(
@@ -112,6 +783,16 @@ impl<'a> Context<'a> {
             };
         }
 
+        // for long and double
+        macro_rules! two_slots_culc {
+            ($method_name:ident) => {
+                let stackframe = self.get_last_stackframe();
+                let (first, second) = stackframe.operand_stack.$method_name();
+                stackframe.operand_stack.stack.push(first);
+                stackframe.operand_stack.stack.push(second);
+            };
+        }
+
         match instruction {
             Instruction::Aconstnull => {
                 let operand_stack = self.get_operand_stack();
@@ -136,43 +817,110 @@ impl<'a> Context<'a> {
                 single_culc!(fmul);
             }
             Instruction::Idiv => {
+                if self.is_divided_by_zero(string_map) {
+                    return (false, index);
+                }
                 single_culc!(idiv);
             }
             Instruction::Fdiv => {
                 single_culc!(fdiv);
             }
             Instruction::Irem => {
+                if self.is_divided_by_zero(string_map) {
+                    return (false, index);
+                }
                 single_culc!(irem);
             }
+            Instruction::Iand => {
+                single_culc!(iand);
+            }
+            Instruction::Ior => {
+                single_culc!(ior);
+            }
+            Instruction::Ixor => {
+                single_culc!(ixor);
+            }
+            Instruction::Ishl => {
+                single_culc!(ishl);
+            }
+            Instruction::Ishr => {
+                single_culc!(ishr);
+            }
+            Instruction::Iushr => {
+                single_culc!(iushr);
+            }
+            Instruction::Ineg => {
+                single_culc!(ineg);
+            }
+            Instruction::Fneg => {
+                single_culc!(fneg);
+            }
+            Instruction::Land => {
+                two_slots_culc!(land);
+            }
+            Instruction::Lor => {
+                two_slots_culc!(lor);
+            }
+            Instruction::Lxor => {
+                two_slots_culc!(lxor);
+            }
+            Instruction::Lshl => {
+                two_slots_culc!(lshl);
+            }
+            Instruction::Lshr => {
+                two_slots_culc!(lshr);
+            }
+            Instruction::Lushr => {
+                two_slots_culc!(lushr);
+            }
+            Instruction::Lneg => {
+                two_slots_culc!(lneg);
+            }
             Instruction::Ladd => {
-                let stackframe = self.get_last_stackframe();
-                let (first, second) = stackframe.operand_stack.ladd();
-                stackframe.operand_stack.stack.push(first);
-                stackframe.operand_stack.stack.push(second);
+                two_slots_culc!(ladd);
             }
             Instruction::Lsub => {
-                let stackframe = self.get_last_stackframe();
-                let (first, second) = stackframe.operand_stack.lsub();
-                stackframe.operand_stack.stack.push(first);
-                stackframe.operand_stack.stack.push(second);
+                two_slots_culc!(lsub);
             }
             Instruction::Lmul => {
-                let stackframe = self.get_last_stackframe();
-                let (first, second) = stackframe.operand_stack.lmul();
-                stackframe.operand_stack.stack.push(first);
-                stackframe.operand_stack.stack.push(second);
+                two_slots_culc!(lmul);
             }
             Instruction::Ldiv => {
-                let stackframe = self.get_last_stackframe();
-                let (first, second) = stackframe.operand_stack.ldiv();
-                stackframe.operand_stack.stack.push(first);
-                stackframe.operand_stack.stack.push(second);
+                if self.is_divided_by_zero(string_map) {
+                    return (false, index);
+                }
+                two_slots_culc!(ldiv);
             }
             Instruction::Lrem => {
-                let stackframe = self.get_last_stackframe();
-                let (first, second) = stackframe.operand_stack.lrem();
-                stackframe.operand_stack.stack.push(first);
-                stackframe.operand_stack.stack.push(second);
+                if self.is_divided_by_zero(string_map) {
+                    return (false, index);
+                }
+                two_slots_culc!(lrem);
+            }
+            Instruction::Dadd => {
+                two_slots_culc!(dadd);
+            }
+            Instruction::Dsub => {
+                two_slots_culc!(dsub);
+            }
+            Instruction::Dmul => {
+                two_slots_culc!(dmul);
+            }
+            Instruction::Ddiv => {
+                two_slots_culc!(ddiv);
+            }
+            Instruction::Drem => {
+                two_slots_culc!(drem);
+            }
+            Instruction::Dneg => {
+                let operand_stack = self.get_operand_stack();
+                match (operand_stack.pop(), operand_stack.pop()) {
+                    (Some(Item::Double(value)), Some(Item::Double(_))) => {
+                        operand_stack.push(Item::Double(-value));
+                        operand_stack.push(Item::Double(-value));
+                    }
+                    items @ _ => unreachable!("should be double. actual: {:?}", items),
+                };
             }
             Instruction::IconstN(val) => {
                 let operand_stack = self.get_operand_stack();
@@ -180,8 +928,8 @@ impl<'a> Context<'a> {
             }
             Instruction::LconstN(val) => {
                 let operand_stack = self.get_operand_stack();
-                operand_stack.push(Item::Long(0));
-                operand_stack.push(Item::Long(*val));
+                operand_stack.push(Item::Long(*val as i64));
+                operand_stack.push(Item::Long(*val as i64));
             }
             Instruction::FconstN(val) => {
                 let operand_stack = self.get_operand_stack();
@@ -189,13 +937,8 @@ impl<'a> Context<'a> {
             }
             Instruction::DconstN(val) => {
                 let operand_stack = self.get_operand_stack();
-                let second = match val {
-                    0 => 0,
-                    1 => 0x3FF00000,
-                    _ => unreachable!(),
-                };
-                operand_stack.push(Item::Double(0));
-                operand_stack.push(Item::Double(second));
+                operand_stack.push(Item::Double(*val as f64));
+                operand_stack.push(Item::Double(*val as f64));
             }
             // maybe need to fix for float or something like that
             Instruction::Bipush(val) => {
@@ -204,21 +947,30 @@ impl<'a> Context<'a> {
             }
             Instruction::Sipush(val) => {
                 let operand_stack = self.get_operand_stack();
-                operand_stack.push(Item::Int(*val as i32));
+                operand_stack.push(Item::Int(*val));
             }
-            Instruction::Lookupswitch(vals) => {
+            Instruction::Tableswitch(default, low, jump_pointers) => {
                 let operand_stack = self.get_operand_stack();
                 if let Some(Item::Int(target_key)) = operand_stack.pop() {
-                    if let Some(jump_pointer) = vals.iter().find(|(optional_key, _)| {
-                        if let Some(key) = *optional_key {
-                            key == target_key as usize
-                        } else {
-                            false
-                        }
-                    }) {
-                        return (false, jump_pointer.1);
+                    let offset = target_key as i64 - *low as i64;
+                    if 0 <= offset && offset < jump_pointers.len() as i64 {
+                        return (false, jump_pointers[offset as usize]);
                     } else {
-                        return (false, vals.first().expect("should exist default value").1);
+                        return (false, *default);
+                    }
+                } else {
+                    unreachable!("should exist operan_item");
+                }
+            }
+            Instruction::Lookupswitch(default, pairs) => {
+                let operand_stack = self.get_operand_stack();
+                if let Some(Item::Int(target_key)) = operand_stack.pop() {
+                    if let Some((_, jump_pointer)) =
+                        pairs.iter().find(|(key, _)| *key == target_key)
+                    {
+                        return (false, *jump_pointer);
+                    } else {
+                        return (false, *default);
                     }
                 } else {
                     unreachable!("should exist operan_item");
@@ -231,10 +983,27 @@ impl<'a> Context<'a> {
                 let stackframe = self.get_last_stackframe();
                 if let Some(item) = stackframe.local_variables.get_mut(*index) {
                     if let Item::Int(val) = item {
-                        mem::replace(val, *val + *value as i32);
+                        *val = val.wrapping_add(*value);
                     }
                 }
             }
+            Instruction::I2l
+            | Instruction::I2f
+            | Instruction::I2d
+            | Instruction::L2i
+            | Instruction::L2f
+            | Instruction::L2d
+            | Instruction::F2i
+            | Instruction::F2l
+            | Instruction::F2d
+            | Instruction::D2i
+            | Instruction::D2l
+            | Instruction::D2f
+            | Instruction::I2b
+            | Instruction::I2c
+            | Instruction::I2s => {
+                self.convert_primitive(instruction);
+            }
             Instruction::Lcmp => {
                 let stackframe = self.get_last_stackframe();
                 let val = stackframe.operand_stack.lcmp();
@@ -247,6 +1016,16 @@ impl<'a> Context<'a> {
             Instruction::Fcmpg => {
                 single_culc!(fcmp);
             }
+            Instruction::Dcmpl => {
+                let stackframe = self.get_last_stackframe();
+                let val = stackframe.operand_stack.dcmp(-1);
+                stackframe.operand_stack.stack.push(val);
+            }
+            Instruction::Dcmpg => {
+                let stackframe = self.get_last_stackframe();
+                let val = stackframe.operand_stack.dcmp(1);
+                stackframe.operand_stack.stack.push(val);
+            }
             Instruction::Ifeq(if_val, else_val) => {
                 let operand_stack = self.get_operand_stack();
                 let val = operand_stack.pop().unwrap();
@@ -349,6 +1128,91 @@ impl<'a> Context<'a> {
                 let jump_pointer = if first <= second { *if_val } else { *else_val };
                 return (false, jump_pointer);
             }
+            Instruction::Ifacmpeq(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let second = operand_stack.pop();
+                let first = operand_stack.pop();
+                let jump_pointer = if first == second { *if_val } else { *else_val };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifacmpne(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let second = operand_stack.pop();
+                let first = operand_stack.pop();
+                let jump_pointer = if first != second { *if_val } else { *else_val };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifnull(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let val = operand_stack.pop().unwrap();
+                let jump_pointer = if val == Item::Null {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifnonnull(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let val = operand_stack.pop().unwrap();
+                let jump_pointer = if val != Item::Null {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
+            Instruction::Athrow => {
+                let operand_stack = self.get_operand_stack();
+                match operand_stack.pop() {
+                    Some(Item::Objectref(object_id)) => {
+                        self.thrown_exception = Some(object_id);
+                    }
+                    Some(Item::Null) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
+                    item @ _ => unreachable!("should be Objectref. actual: {:?}", item),
+                };
+                return (false, index);
+            }
+            // the objectref is left on the operand stack as it is
+            Instruction::Checkcast(class_index) => {
+                let item = self
+                    .get_operand_stack()
+                    .last()
+                    .cloned()
+                    .expect("should exist item in operand_stack");
+                if let Some(class_name) = self.get_item_class_name(string_map, &item) {
+                    let target_class_name = class_file.cp_info.get_class_ref_name(*class_index);
+                    if !self.is_assignable(string_map, class_file, class_name, target_class_name) {
+                        let message = format!(
+                            "class {} cannot be cast to class {}",
+                            string_map.get_value(&class_name).replace("/", "."),
+                            string_map.get_value(&target_class_name).replace("/", ".")
+                        );
+                        self.throw_exception(
+                            string_map,
+                            "java/lang/ClassCastException",
+                            Some(message),
+                        );
+                    }
+                }
+            }
+            Instruction::Instanceof(class_index) => {
+                let item = self
+                    .get_operand_stack()
+                    .pop()
+                    .expect("should exist item in operand_stack");
+                let result = match self.get_item_class_name(string_map, &item) {
+                    Some(class_name) => {
+                        let target_class_name = class_file.cp_info.get_class_ref_name(*class_index);
+                        self.is_assignable(string_map, class_file, class_name, target_class_name)
+                    }
+                    None => false,
+                };
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::Int(result as i32));
+            }
             Instruction::Iload(index) => {
                 self.load_n(*index);
             }
@@ -358,10 +1222,15 @@ impl<'a> Context<'a> {
             Instruction::IloadN(index) => {
                 self.load_n(*index);
             }
-            Instruction::FloadN(index) => {
+            Instruction::Fload(index) | Instruction::FloadN(index) => {
                 self.load_n(*index);
             }
-            Instruction::LloadN(index) => {
+            Instruction::Lload(index) | Instruction::LloadN(index) => {
+                let base_index = *index;
+                self.load_n(base_index);
+                self.load_n(base_index + 1);
+            }
+            Instruction::Dload(index) => {
                 let base_index = *index;
                 self.load_n(base_index);
                 self.load_n(base_index + 1);
@@ -377,7 +1246,11 @@ impl<'a> Context<'a> {
             Instruction::IstoreN(index) => {
                 self.store_n(&[*index as usize]);
             }
-            Instruction::LstoreN(index) => {
+            Instruction::Lstore(index) | Instruction::LstoreN(index) => {
+                let base_index = *index;
+                self.store_n(&[base_index + 1, base_index]);
+            }
+            Instruction::Dstore(index) => {
                 let base_index = *index;
                 self.store_n(&[base_index + 1, base_index]);
             }
@@ -385,58 +1258,61 @@ impl<'a> Context<'a> {
                 let base_index = *index;
                 self.store_n(&[base_index + 1, base_index]);
             }
-            Instruction::FstoreN(index) => {
+            Instruction::Fstore(index) | Instruction::FstoreN(index) => {
                 self.store_n(&[*index]);
             }
             Instruction::AloadN(index) => {
                 self.load_n(*index);
             }
-            Instruction::Iaload => {
-                self.n_aload();
-            }
-            Instruction::Laload => {
-                self.n_aload();
-            }
-            Instruction::Baload => {
-                self.n_aload();
+            Instruction::Iaload
+            | Instruction::Laload
+            | Instruction::Faload
+            | Instruction::Daload
+            | Instruction::Baload
+            | Instruction::Caload
+            | Instruction::Saload => {
+                self.n_aload(string_map);
             }
             Instruction::Aaload => {
                 let operand_stack = self.get_operand_stack();
                 match (operand_stack.pop(), operand_stack.pop()) {
-                    (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                    (Some(Item::Int(array_index)), Some(Item::Arrayref(array_ref_id))) => {
+                        let array_index =
+                            match self.check_array_index(string_map, array_ref_id, array_index) {
+                                Some(array_index) => array_index,
+                                None => return (false, index),
+                            };
                         let array_cell = self
                             .array_map
                             .get_mut(&array_ref_id)
                             .expect("should exist item in array_map");
 
                         let item = match array_cell {
-                            Array::Custom(items) => {
-                                let object_id = items.borrow()[index as usize];
-                                Item::Objectref(object_id)
-                            }
-                            Array::Array(ids) => {
-                                let array_id = ids.borrow()[index as usize];
-                                Item::Arrayref(array_id)
+                            Array::Custom(items) | Array::Array(items) => {
+                                items.borrow()[array_index].clone()
                             }
                             _ => unreachable!("Aaload doesn't handle Array::Primitive"),
                         };
                         let operand_stack = self.get_operand_stack();
                         operand_stack.push(item);
                     }
+                    (Some(Item::Int(_)), Some(Item::Null)) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
                     _ => unreachable!("should exist two items in operand_stack"),
                 };
             }
             Instruction::Astore(index) => {
                 self.store_n(&[*index]);
             }
-            Instruction::Iastore => {
-                self.x_astore();
-            }
-            Instruction::Lastore => {
-                self.x_astore();
-            }
-            Instruction::Bastore => {
-                self.x_astore();
+            Instruction::Iastore
+            | Instruction::Lastore
+            | Instruction::Fastore
+            | Instruction::Dastore
+            | Instruction::Bastore
+            | Instruction::Castore
+            | Instruction::Sastore => {
+                self.x_astore(string_map, instruction);
             }
             Instruction::Aastore => {
                 let operand_stack = self.get_operand_stack();
@@ -445,29 +1321,42 @@ impl<'a> Context<'a> {
                     operand_stack.pop(),
                     operand_stack.pop(),
                 ) {
-                    (Some(item), Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                    (
+                        Some(item),
+                        Some(Item::Int(array_index)),
+                        Some(Item::Arrayref(array_ref_id)),
+                    ) => {
+                        let array_index =
+                            match self.check_array_index(string_map, array_ref_id, array_index) {
+                                Some(array_index) => array_index,
+                                None => return (false, index),
+                            };
+                        if !self.is_storable(string_map, class_file, array_ref_id, &item) {
+                            let class_name = self
+                                .get_item_class_name(string_map, &item)
+                                .expect("should be the class of the item");
+                            let message = string_map.get_value(&class_name).replace("/", ".");
+                            self.throw_exception(
+                                string_map,
+                                "java/lang/ArrayStoreException",
+                                Some(message),
+                            );
+                            return (false, index);
+                        }
                         if let Some(array_cell) = self.array_map.get_mut(&array_ref_id) {
-                            match item {
-                                Item::Objectref(ref_id) => {
-                                    match array_cell {
-                                        Array::Custom(items) => {
-                                            items.borrow_mut()[index as usize] = ref_id;
-                                        }
-                                        _ => unimplemented!(),
-                                    };
+                            match array_cell {
+                                Array::Custom(items) | Array::Array(items) => {
+                                    items.borrow_mut()[array_index] = item;
                                 }
-                                Item::Arrayref(ref_id) => {
-                                    match array_cell {
-                                        Array::Array(items) => {
-                                            items.borrow_mut()[index as usize] = ref_id;
-                                        }
-                                        _ => unimplemented!(),
-                                    };
+                                Array::Primitive(_) => {
+                                    unreachable!("aastore should store into the reference array")
                                 }
-                                _ => unimplemented!(),
                             };
                         }
                     }
+                    (Some(_), Some(Item::Int(_)), Some(Item::Null)) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
                     items @ _ => panic!(
                         "should exist three items in operand_stack
 ${:?}",
@@ -478,120 +1367,236 @@ ${:?}",
             Instruction::AstoreN(index) => {
                 self.store_n(&[*index]);
             }
-            Instruction::Putstatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, field_name) = self.get_class_and_field_name(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
+            Instruction::Putstatic(field_index) => {
+                let (class_name, slot) =
+                    self.resolve_field_ref(string_map, class_file, *field_index, true);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
 
                 let operand_stack = self.get_operand_stack();
                 let (first, second) = match operand_stack.pop() {
-                    Some(second @ Item::Long(_)) => {
+                    Some(second) if second.is_category_2() => {
                         let first = operand_stack.pop().unwrap();
                         (first, second)
                     }
                     first @ _ => (first.unwrap(), Item::Null),
                 };
-                self.static_fields
-                    .insert((class_name, field_name), (first, second));
+                self.static_fields[slot] = (first, second);
             }
-            Instruction::Getstatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, field_name) = self.get_class_and_field_name(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
+            Instruction::Getstatic(field_index) => {
+                let (class_name, slot) =
+                    self.resolve_field_ref(string_map, class_file, *field_index, true);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
 
-                let err_message = format!(
-                    "Getstatic failed. {}.{} is not found",
-                    &class_name, &field_name
-                );
-                let items = self
-                    .static_fields
-                    .get_mut(&(class_name, field_name))
-                    .expect(&err_message)
-                    .clone();
+                let items = self.static_fields[slot].clone();
 
                 let operand_stack = self.get_operand_stack();
-                match items.0 {
-                    Item::Long(_) => {
-                        operand_stack.push(items.0);
-                        operand_stack.push(items.1);
-                    }
-                    _ => operand_stack.push(items.0),
-                };
-            }
-            Instruction::Areturn | Instruction::Ireturn => {
-                let operand_stack = self.get_operand_stack();
-                let item = if let Some(item) = operand_stack.pop() {
-                    operand_stack.clear();
-                    item
-                } else {
-                    unreachable!("should exist return value on operand_stack")
-                };
-                let length = self.stack_frames.len();
-                if let Some(stackframe) = self.stack_frames.get_mut(length - 2) {
-                    stackframe.operand_stack.stack.push(item);
+                if items.0.is_category_2() {
+                    operand_stack.push(items.0);
+                    operand_stack.push(items.1);
                 } else {
-                    unreachable!("should exist over two stack_frame");
+                    operand_stack.push(items.0);
                 }
+            }
+            Instruction::Areturn | Instruction::Ireturn | Instruction::Freturn => {
+                self.return_value(1);
+                return (true, index);
+            }
+            Instruction::Lreturn | Instruction::Dreturn => {
+                self.return_value(2);
                 return (true, index);
             }
             Instruction::Pop => {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.pop();
             }
+            Instruction::Pop2 => {
+                let operand_stack = self.get_operand_stack();
+                operand_stack.pop();
+                operand_stack.pop();
+            }
             Instruction::Dup => {
+                self.get_last_stackframe().operand_stack.dup_x(1, 0);
+            }
+            Instruction::DupX1 => {
+                self.get_last_stackframe().operand_stack.dup_x(1, 1);
+            }
+            Instruction::DupX2 => {
+                self.get_last_stackframe().operand_stack.dup_x(1, 2);
+            }
+            Instruction::Dup2 => {
+                self.get_last_stackframe().operand_stack.dup_x(2, 0);
+            }
+            Instruction::Dup2X1 => {
+                self.get_last_stackframe().operand_stack.dup_x(2, 1);
+            }
+            Instruction::Dup2X2 => {
+                self.get_last_stackframe().operand_stack.dup_x(2, 2);
+            }
+            Instruction::Swap => {
                 let operand_stack = self.get_operand_stack();
-                let last = if let Some(last) = operand_stack.last() {
-                    last.clone()
+                let length = operand_stack.len();
+                operand_stack.swap(length - 1, length - 2);
+            }
+            Instruction::Invokevirtual(method_index)
+            | Instruction::Invokeinterface(method_index, _) => {
+                let method = self.resolve_method_ref(string_map, class_file, *method_index);
+                let ResolvedMethod {
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                    parameter_slot_count,
+                    is_private,
+                    is_array,
+                } = method;
+                if self.is_null_receiver(parameter_slot_count) {
+                    self.throw_null_pointer_exception(string_map);
+                    return (false, index);
+                }
+                // the arrays only override clone of java/lang/Object. e.g. values() of enum
+                if is_array {
+                    match string_map.get_value(&method_name).as_ref() {
+                        "clone" => self.clone_array(string_map),
+                        method_name => unimplemented!("{} of array is not supported", method_name),
+                    }
+                    return (false, index + instruction.counsume_index());
+                }
+                let is_lambda_method = self
+                    .get_receiver_object_id(parameter_slot_count)
+                    .and_then(|object_id| self.lambda_map.get(&object_id))
+                    .map_or(false, |lambda| lambda.interface_method_name == method_name);
+                if is_lambda_method {
+                    self.call_lambda(string_map, class_file, method_descriptor);
                 } else {
-                    unreachable!("should have an item at least");
+                    // the method is selected from the runtime class of the receiver.
+                    // private methods are selected from the resolved class. JVMS 5.4.6
+                    let receiver_class_name = if is_private {
+                        class_name
+                    } else {
+                        self.get_receiver_class_name(parameter_slot_count)
+                            .unwrap_or(class_name)
+                    };
+                    let (class_name, method_index) = match self.select_method_class(
+                        string_map,
+                        class_file,
+                        index,
+                        receiver_class_name,
+                        method_name,
+                        method_descriptor,
+                    ) {
+                        Some(selected_method) => selected_method,
+                        None => return (false, index),
+                    };
+                    self.call_resolved_method(
+                        string_map,
+                        &class_file,
+                        ResolvedMethod {
+                            class_name,
+                            ..method
+                        },
+                        method_index,
+                    );
+                }
+            }
+            // for <init>, private methods and super.method()
+            Instruction::Invokespecial(method_index) => {
+                let method = self.resolve_method_ref(string_map, class_file, *method_index);
+                let ResolvedMethod {
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                    parameter_slot_count,
+                    ..
+                } = method;
+                if self.is_null_receiver(parameter_slot_count) {
+                    self.throw_null_pointer_exception(string_map);
+                    return (false, index);
+                }
+                let (class_name, method_index) = match self.select_method_class(
+                    string_map,
+                    class_file,
+                    index,
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                ) {
+                    Some(selected_method) => selected_method,
+                    None => return (false, index),
                 };
-                operand_stack.push(last);
+                self.call_resolved_method(
+                    string_map,
+                    &class_file,
+                    ResolvedMethod {
+                        class_name,
+                        ..method
+                    },
+                    method_index,
+                );
             }
-            Instruction::Invokevirtual(index) | Instruction::Invokespecial(index) => {
-                let (class_name, name_and_type) = self.get_related_method_info(class_file, *index);
-                self.call_method(string_map, &class_file, class_name, name_and_type);
+            Instruction::Invokestatic(method_index) => {
+                let method = self.resolve_method_ref(string_map, class_file, *method_index);
+                let ResolvedMethod {
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                    ..
+                } = method;
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
+                let (class_name, method_index) = match self.select_method_class(
+                    string_map,
+                    class_file,
+                    index,
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                ) {
+                    Some(selected_method) => selected_method,
+                    None => return (false, index),
+                };
+                self.call_resolved_method(
+                    string_map,
+                    &class_file,
+                    ResolvedMethod {
+                        class_name,
+                        ..method
+                    },
+                    method_index,
+                );
             }
-            Instruction::Invokestatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, name_and_type) = self.get_related_method_info(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
-                self.call_method(string_map, &class_file, class_name, name_and_type);
+            Instruction::Invokedynamic(index) => {
+                self.invoke_dynamic(string_map, class_file, *index);
             }
-            Instruction::Putfield(index) => {
-                let (class_name, field_name) = class_file.cp_info.get_class_and_field_name(*index);
+            Instruction::Putfield(field_index) => {
+                let (_, slot) = self.resolve_field_ref(string_map, class_file, *field_index, false);
                 let vals = self.get_field_tupple();
 
                 let item = self
                     .get_operand_stack()
                     .pop()
                     .expect("should exist item in operand_stack");
-                let obj_id = match item {
+                match item {
                     Item::Objectref(obj_id) => {
                         let obj_ref = self
                             .object_map
                             .get_mut(&obj_id)
                             .expect("should exist object_ref in object_map");
 
-                        {
-                            assert!(
-                                class_name == obj_ref.class_name_id,
-                                "should be equal class_name"
-                            );
-                        }
-
-                        obj_ref
-                            .field_map
-                            .borrow_mut()
-                            .insert((field_name, obj_id), vals);
-                        obj_id
+                        obj_ref.fields.borrow_mut()[slot] = vals;
+                    }
+                    Item::Null => {
+                        self.throw_null_pointer_exception(string_map);
+                        return (false, index);
                     }
                     item @ _ => unreachable!("should be Objectref. actual: {}", item),
                 };
-                let operand_stack = self.get_operand_stack();
-                operand_stack.push(Item::Objectref(obj_id));
             }
-            Instruction::Getfield(index) => {
-                let (class_name, field_name) = class_file.cp_info.get_class_and_field_name(*index);
+            Instruction::Getfield(field_index) => {
+                let (_, slot) = self.resolve_field_ref(string_map, class_file, *field_index, false);
 
                 let item = self
                     .get_operand_stack()
@@ -604,35 +1609,25 @@ ${:?}",
                             .get(&obj_id)
                             .expect("should exist objectref in object_map");
 
-                        {
-                            assert!(
-                                class_name == obj_ref.class_name_id,
-                                "should be equal class_name"
-                            );
-                        }
-
-                        let field_map = obj_ref.field_map.borrow();
-                        let (first, second) = field_map
-                            .get(&(field_name, obj_id))
-                            .expect("should exist item")
-                            .clone();
-                        (first, second)
+                        let fields = obj_ref.fields.borrow();
+                        fields[slot].clone()
+                    }
+                    Item::Null => {
+                        self.throw_null_pointer_exception(string_map);
+                        return (false, index);
                     }
                     item @ _ => unreachable!("should be Objectref. actual: {}", item),
                 };
 
                 let operand_stack = self.get_operand_stack();
-                match first {
-                    Item::Long(_) => {
-                        operand_stack.push(first);
-                        operand_stack.push(second.clone());
-                    }
-                    item @ _ => {
-                        operand_stack.push(item);
-                    }
+                if first.is_category_2() {
+                    operand_stack.push(first);
+                    operand_stack.push(second);
+                } else {
+                    operand_stack.push(first);
                 }
             }
-            Instruction::Ldc(index) => {
+            Instruction::Ldc(index) | Instruction::LdcW(index) => {
                 let index_value = *index;
                 let operand_stack = self.get_operand_stack();
                 match class_file.cp_info.get_item_tag(index_value) {
@@ -640,10 +1635,19 @@ ${:?}",
                         let val = class_file.cp_info.get_string(index_value);
                         operand_stack.push(Item::String(val));
                     }
+                    ConstPoolTag::ConstantInteger => {
+                        let val = class_file.cp_info.get_integer(index_value);
+                        operand_stack.push(Item::Int(val));
+                    }
                     ConstPoolTag::ConstantFloat => {
                         let val = class_file.cp_info.get_float(index_value);
                         operand_stack.push(Item::Float(val));
                     }
+                    // there is no Class object. the class is expressed by the name. e.g. Foo.class
+                    ConstPoolTag::ConstantClass => {
+                        let val = class_file.cp_info.get_class_ref_name(index_value);
+                        operand_stack.push(Item::Classref(val));
+                    }
                     _ => unimplemented!(),
                 };
             }
@@ -654,38 +1658,42 @@ ${:?}",
                     (*first << 8 | *second) & 0xFFFF,
                 );
             }
-            Instruction::New(index) => {
-                let this_class_name = class_file.this_class_name();
-                let class_ref = class_file.cp_info.get_class_ref(*index);
+            Instruction::New(class_index) => {
+                let class_ref = class_file.cp_info.get_class_ref(*class_index);
                 let class_name = class_file.cp_info.get_utf8(class_ref.name_index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
-
-                let object_ref = if let Some(JavaClass::Custom(target_class)) =
-                    self.class_map.get(&class_name)
-                {
-                    let mut field_map = HashMap::new();
-                    for (index, field) in target_class.fields.iter().enumerate() {
-                        let field_name = target_class.cp_info.get_utf8(field.name_index);
-                        let descriptor = target_class
-                            .cp_info
-                            .get_utf8_as_string(string_map, field.descriptor_index);
-                        let vals =
-                            create_uninitialized_item(&FieldDescriptor::from(descriptor.as_ref()));
-                        field_map.insert((field_name, index), vals);
-                    }
-                    Objectref::new(class_name, RefCell::new(field_map), true)
-                } else {
-                    unreachable!("not come here")
-                };
-                let id = self.object_map.add(object_ref);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
+                let field_count = self
+                    .get_field_layout(string_map, class_name)
+                    .initial_values
+                    .len();
+                if !self.reserve_heap(string_map, get_object_size(field_count)) {
+                    return (false, index);
+                }
+                let id = self.create_object(string_map, class_name);
                 let operand_stack = self.get_operand_stack();
                 operand_stack.push(Item::Objectref(id));
             }
             Instruction::Newarray(type_index) => {
                 let id = if let Some(Item::Int(length)) = self.get_operand_stack().pop() {
+                    if length < 0 {
+                        self.throw_negative_array_size(string_map, length);
+                        return (false, index);
+                    }
+                    let size =
+                        get_array_size(length as usize, get_primitive_element_size(*type_index));
+                    if !self.reserve_heap(string_map, size) {
+                        return (false, index);
+                    }
                     let default_array = iniailize_primitive_array(*type_index, length as usize);
-                    self.array_map
-                        .add(Array::Primitive(RefCell::new(default_array)))
+                    let array_class_name = string_map
+                        .insert(format!("[{}", get_primitive_array_descriptor(*type_index)));
+                    self.array_map.add(
+                        Array::Primitive(RefCell::new(default_array)),
+                        array_class_name,
+                        size,
+                    )
                 } else {
                     unreachable!("should exist item in operand_stack")
                 };
@@ -694,16 +1702,25 @@ ${:?}",
                 operand_stack.push(Item::Arrayref(id));
             }
             // class, array, or interface type
-            Instruction::Anewarray(index) => {
-                let class_name = class_file.cp_info.get_class_ref_name(*index);
+            Instruction::Anewarray(class_index) => {
+                let class_name = class_file.cp_info.get_class_ref_name(*class_index);
                 let id = if let Some(Item::Int(length)) = self.get_operand_stack().pop() {
-                    let default_array = initialize_objectref_array(
-                        &mut self.object_map,
-                        class_name,
-                        length as usize,
-                    );
-                    self.array_map
-                        .add(Array::Custom(RefCell::new(default_array)))
+                    if length < 0 {
+                        self.throw_negative_array_size(string_map, length);
+                        return (false, index);
+                    }
+                    let size = get_array_size(length as usize, CUSTOM_ELEMENT_SIZE);
+                    if !self.reserve_heap(string_map, size) {
+                        return (false, index);
+                    }
+                    let default_array = vec![Item::Null; length as usize];
+                    let array_class_name =
+                        string_map.insert(to_array_class_name(&string_map.get_value(&class_name)));
+                    self.array_map.add(
+                        Array::Custom(RefCell::new(default_array)),
+                        array_class_name,
+                        size,
+                    )
                 } else {
                     unreachable!("should exist item in operand_stack")
                 };
@@ -714,51 +1731,89 @@ ${:?}",
             Instruction::Return => {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.clear();
+                return (true, index);
             }
-            Instruction::Multianewarray(index, dimentions) => {
+            Instruction::Arraylength => {
+                let operand_stack = self.get_operand_stack();
+                match operand_stack.pop() {
+                    Some(Item::Arrayref(array_ref_id)) => {
+                        let length = self
+                            .array_map
+                            .get(&array_ref_id)
+                            .expect("should exist item in array_map")
+                            .len();
+                        let operand_stack = self.get_operand_stack();
+                        operand_stack.push(Item::Int(length as i32));
+                    }
+                    Some(Item::Null) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
+                    item @ _ => unreachable!("should be Arrayref. actual: {:?}", item),
+                };
+            }
+            Instruction::Multianewarray(class_index, dimentions) => {
                 let operand_stack = self.get_operand_stack();
                 let operand_stack_len = operand_stack.len();
-                let mut counts: Vec<usize> = operand_stack
+                let counts: Vec<i32> = operand_stack
                     .drain(operand_stack_len - dimentions..operand_stack_len)
                     .map(|item| {
                         if let Item::Int(val) = item {
-                            val as usize
+                            val
                         } else {
                             unreachable!("Item should be int")
                         }
                     })
                     .collect();
-
+                if let Some(length) = counts.iter().find(|count| **count < 0) {
+                    self.throw_negative_array_size(string_map, *length);
+                    return (false, index);
+                }
+                let mut counts: Vec<usize> =
+                    counts.into_iter().map(|count| count as usize).collect();
                 let dimentions = *dimentions;
-                let class_array_name_id = class_file.cp_info.get_class_ref_name(*index);
+                let class_array_name_id = class_file.cp_info.get_class_ref_name(*class_index);
                 let class_array_name = string_map.get_value(&class_array_name_id);
                 let class_name = &class_array_name[dimentions..];
+                // the leaves hold references if the dimensions are fewer than the array type has
+                let leaf_element_size = match &class_array_name[counts.len()..counts.len() + 1] {
+                    "L" | "[" => CUSTOM_ELEMENT_SIZE,
+                    descriptor => get_primitive_element_size(get_primitive_array_type(descriptor)),
+                };
+                if !self.reserve_heap(
+                    string_map,
+                    get_multi_dimentions_array_size(&counts, leaf_element_size),
+                ) {
+                    return (false, index);
+                }
+
+                // the class names of the arrays on each dimension. e.g. [[I, [I
+                let array_class_names: Vec<usize> = (0..dimentions)
+                    .map(|dimention| string_map.insert(class_array_name[dimention..].to_string()))
+                    .collect();
                 match &class_name[0..1] {
-                    // for class
-                    "L" => {
-                        let class_name_len = class_name.len();
-                        let actual_class_name = &class_name[1..class_name_len - 1];
+                    // for class. the innermost arrays are filled with null if the dimensions are
+                    // fewer than the array type has. e.g. new int[2][3][]
+                    "L" | "[" => {
                         let first_count = counts.first().unwrap().clone();
-                        let string_id = string_map.insert(actual_class_name.to_string());
 
                         let multi_dimentions_id = self.create_multi_dimentions_custom_array(
                             &mut counts,
                             1, // default should be 1
                             first_count,
-                            string_id,
+                            &array_class_names,
                         );
                         let operand_stack = self.get_operand_stack();
                         operand_stack.push(Item::Arrayref(multi_dimentions_id));
                     }
                     discriptor @ _ => {
-                        let initial_val =
-                            create_uninitialized_item(&FieldDescriptor::from(discriptor));
+                        let type_index = get_primitive_array_type(discriptor);
                         let first_count = counts.first().unwrap().clone();
                         let multi_dimentions_id = self.create_multi_dimentions_array(
                             &mut counts,
                             1, // default should be 1
                             first_count,
-                            initial_val,
+                            type_index,
+                            &array_class_names,
                         );
 
                         let operand_stack = self.get_operand_stack();
@@ -771,57 +1826,177 @@ ${:?}",
         (false, index + instruction.counsume_index())
     }
 
-    fn x_astore(&mut self) {
+    // float to integer conversions are saturated and NaN becomes 0. it is the same as Rust's as
+    fn convert_primitive(&mut self, instruction: &Instruction) {
+        let operand_stack = self.get_operand_stack();
+        let value = operand_stack
+            .pop()
+            .expect("should exist item in operand_stack");
+        if value.is_category_2() {
+            operand_stack.pop();
+        }
+
+        let converted = match (instruction, value) {
+            (Instruction::I2l, Item::Int(value)) => Item::Long(value as i64),
+            (Instruction::I2f, Item::Int(value)) => Item::Float(value as f32),
+            (Instruction::I2d, Item::Int(value)) => Item::Double(value as f64),
+            (Instruction::L2i, Item::Long(value)) => Item::Int(value as i32),
+            (Instruction::L2f, Item::Long(value)) => Item::Float(value as f32),
+            (Instruction::L2d, Item::Long(value)) => Item::Double(value as f64),
+            (Instruction::F2i, Item::Float(value)) => Item::Int(value as i32),
+            (Instruction::F2l, Item::Float(value)) => Item::Long(value as i64),
+            (Instruction::F2d, Item::Float(value)) => Item::Double(value as f64),
+            (Instruction::D2i, Item::Double(value)) => Item::Int(value as i32),
+            (Instruction::D2l, Item::Double(value)) => Item::Long(value as i64),
+            (Instruction::D2f, Item::Double(value)) => Item::Float(value as f32),
+            (Instruction::I2b, Item::Int(value)) => Item::Int(value as i8 as i32),
+            (Instruction::I2c, Item::Int(value)) => Item::Int(value as u16 as i32),
+            (Instruction::I2s, Item::Int(value)) => Item::Int(value as i16 as i32),
+            (instruction, value) => unreachable!("{} can't convert {:?}", instruction, value),
+        };
+        if converted.is_category_2() {
+            operand_stack.push(converted.clone());
+        }
+        operand_stack.push(converted);
+    }
+
+    fn x_astore(&mut self, string_map: &mut StringPool, instruction: &Instruction) {
         let operand_stack = self.get_operand_stack();
         let value = operand_stack.pop().expect("should exist item");
-        let values = match value {
-            item @ Item::Long(_) => (item, operand_stack.pop().expect("should exist item")),
-            item @ _ => (item, Item::Null),
+        let values = if value.is_category_2() {
+            (operand_stack.pop().expect("should exist item"), value)
+        } else {
+            (value, Item::Null)
         };
 
         match (operand_stack.pop(), operand_stack.pop()) {
             (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                let index = match self.check_array_index(string_map, array_ref_id, index) {
+                    Some(index) => index,
+                    None => return,
+                };
                 if let Some(array_cell) = self.array_map.get_mut(&array_ref_id) {
                     match array_cell {
                         Array::Primitive(items) => {
-                            items.borrow_mut()[index as usize] = values;
-                        }
-                        Array::Array(items) => {
-                            if let Item::Int(val) = values.0 {
-                                items.borrow_mut()[index as usize] = val as usize;
-                            } else {
-                                unreachable!("should int to set Array::Array item")
-                            }
+                            let mut items = items.borrow_mut();
+                            items[index] = truncate_array_item(instruction, &items[index], values);
                         }
                         _ => unimplemented!(),
                     };
                 }
             }
+            (Some(Item::Int(_)), Some(Item::Null)) => {
+                self.throw_null_pointer_exception(string_map);
+            }
             _ => unreachable!("should exist three items in operand_stack"),
         };
     }
 
-    fn n_aload(&mut self) {
+    fn n_aload(&mut self, string_map: &mut StringPool) {
         let operand_stack = self.get_operand_stack();
         match (operand_stack.pop(), operand_stack.pop()) {
             (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                let index = match self.check_array_index(string_map, array_ref_id, index) {
+                    Some(index) => index,
+                    None => return,
+                };
                 let array_cell = self
                     .array_map
                     .get_mut(&array_ref_id)
                     .expect("should exist item in array_map");
                 let item = match array_cell {
-                    Array::Primitive(items) => items.borrow()[index as usize].clone(),
+                    Array::Primitive(items) => items.borrow()[index].clone(),
                     _ => unimplemented!(),
                 };
                 let operand_stack = self.get_operand_stack();
-                operand_stack.push(item.0);
-                match item.1 {
-                    Item::Null => {}
-                    _ => operand_stack.push(item.1),
+                match item {
+                    (Item::Boolean(value), _) => operand_stack.push(Item::Int(value as i32)),
+                    (first, Item::Null) => operand_stack.push(first),
+                    (first, second) => {
+                        operand_stack.push(first);
+                        operand_stack.push(second);
+                    }
                 };
             }
-            _ => panic!("should exist two items in operand_stack"),
+            (Some(Item::Int(_)), Some(Item::Null)) => {
+                self.throw_null_pointer_exception(string_map);
+            }
+            _ => panic!("should exist two items in operand_stack"),
+        };
+    }
+
+    // returns None if ArrayIndexOutOfBoundsException is thrown
+    fn check_array_index(
+        &mut self,
+        string_map: &mut StringPool,
+        array_ref_id: usize,
+        index: i32,
+    ) -> Option<usize> {
+        let length = self
+            .array_map
+            .get(&array_ref_id)
+            .expect("should exist item in array_map")
+            .len();
+        if 0 <= index && (index as usize) < length {
+            Some(index as usize)
+        } else {
+            let message = format!("Index {} out of bounds for length {}", index, length);
+            self.throw_exception(
+                string_map,
+                "java/lang/ArrayIndexOutOfBoundsException",
+                Some(message),
+            );
+            None
+        }
+    }
+
+    // the divisor is on the top of operand_stack
+    fn is_divided_by_zero(&mut self, string_map: &mut StringPool) -> bool {
+        let operand_stack = self.get_operand_stack();
+        match operand_stack.last() {
+            Some(Item::Int(0)) | Some(Item::Long(0)) => {
+                self.throw_exception(
+                    string_map,
+                    "java/lang/ArithmeticException",
+                    Some(String::from("/ by zero")),
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn throw_null_pointer_exception(&mut self, string_map: &mut StringPool) {
+        self.throw_exception(string_map, "java/lang/NullPointerException", None);
+    }
+
+    fn throw_negative_array_size(&mut self, string_map: &mut StringPool, length: i32) {
+        self.throw_exception(
+            string_map,
+            "java/lang/NegativeArraySizeException",
+            Some(length.to_string()),
+        );
+    }
+
+    // for the exceptions which are thrown by the JVM itself. e.g. NullPointerException
+    fn throw_exception(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: &str,
+        message: Option<String>,
+    ) {
+        let class_name_id = string_map.insert(String::from(class_name));
+        let message = match message {
+            Some(message) => Item::String(string_map.insert(message)),
+            None => Item::Null,
         };
+        let object_id = self.create_object(string_map, class_name_id);
+        self.object_map
+            .get(&object_id)
+            .expect("should exist exception in object_map")
+            .fields
+            .borrow_mut()[THROWABLE_DETAIL_MESSAGE_SLOT] = (message, Item::Null);
+        self.thrown_exception = Some(object_id);
     }
 
     fn create_multi_dimentions_custom_array(
@@ -829,21 +2004,18 @@ ${:?}",
         counts: &mut Vec<usize>,
         current_index: usize,
         current_size: usize,
-        class_name_id: usize,
+        array_class_names: &[usize],
     ) -> usize {
+        let array_class_name = array_class_names[current_index - 1];
         let next_size = if let Some(next_size) = counts.get(current_index) {
             next_size.clone()
         } else {
-            let mut items = Vec::with_capacity(current_size);
-            for _ in 0..current_size {
-                let id = self.object_map.add(Objectref::new(
-                    class_name_id,
-                    RefCell::new(HashMap::new()),
-                    false,
-                ));
-                items.push(id);
-            }
-            return self.array_map.add(Array::Custom(RefCell::new(items)));
+            let items = vec![Item::Null; current_size];
+            return self.array_map.add(
+                Array::Custom(RefCell::new(items)),
+                array_class_name,
+                get_array_size(current_size, CUSTOM_ELEMENT_SIZE),
+            );
         };
 
         let mut ids = Vec::with_capacity(current_size);
@@ -852,12 +2024,16 @@ ${:?}",
                 counts,
                 current_index + 1,
                 next_size,
-                class_name_id,
+                array_class_names,
             );
-            ids.push(input_id);
+            ids.push(Item::Arrayref(input_id));
         }
 
-        self.array_map.add(Array::Array(RefCell::new(ids)))
+        self.array_map.add(
+            Array::Array(RefCell::new(ids)),
+            array_class_name,
+            get_array_size(current_size, ARRAY_ELEMENT_SIZE),
+        )
     }
 
     fn create_multi_dimentions_array(
@@ -865,12 +2041,14 @@ ${:?}",
         counts: &mut Vec<usize>,
         current_index: usize,
         current_size: usize,
-        initial_value: (Item, Item),
+        type_index: usize,
+        array_class_names: &[usize],
     ) -> usize {
         let next_size = if let Some(next_size) = counts.get(current_index) {
             next_size.clone()
         } else {
-            return self.create_leaf_child(current_size, initial_value);
+            let array_class_name = array_class_names[current_index - 1];
+            return self.create_leaf_child(current_size, type_index, array_class_name);
         };
 
         self.create_other_dimention(
@@ -878,16 +2056,23 @@ ${:?}",
             current_index,
             current_size,
             next_size,
-            initial_value,
+            type_index,
+            array_class_names,
         )
     }
 
-    fn create_leaf_child(&mut self, current_size: usize, initial_value: (Item, Item)) -> usize {
-        let mut items = Vec::with_capacity(current_size);
-        for _ in 0..current_size {
-            items.push(initial_value.clone());
-        }
-        self.array_map.add(Array::Primitive(RefCell::new(items)))
+    fn create_leaf_child(
+        &mut self,
+        current_size: usize,
+        type_index: usize,
+        array_class_name: usize,
+    ) -> usize {
+        let items = iniailize_primitive_array(type_index, current_size);
+        self.array_map.add(
+            Array::Primitive(RefCell::new(items)),
+            array_class_name,
+            get_array_size(current_size, get_primitive_element_size(type_index)),
+        )
     }
 
     fn create_other_dimention(
@@ -896,7 +2081,8 @@ ${:?}",
         current_index: usize,
         current_size: usize,
         next_size: usize,
-        initial_value: (Item, Item),
+        type_index: usize,
+        array_class_names: &[usize],
     ) -> usize {
         let mut ids = Vec::with_capacity(current_size);
         for _ in 0..current_size {
@@ -904,11 +2090,17 @@ ${:?}",
                 counts,
                 current_index + 1,
                 next_size,
-                initial_value.clone(),
+                type_index,
+                array_class_names,
             );
-            ids.push(input_id);
+            ids.push(Item::Arrayref(input_id));
         }
-        self.array_map.add(Array::Array(RefCell::new(ids)))
+        let array_class_name = array_class_names[current_index - 1];
+        self.array_map.add(
+            Array::Array(RefCell::new(ids)),
+            array_class_name,
+            get_array_size(current_size, ARRAY_ELEMENT_SIZE),
+        )
     }
 
     fn get_field_tupple(&mut self) -> (Item, Item) {
@@ -916,13 +2108,15 @@ ${:?}",
         let first = operand_stack
             .pop()
             .expect("should exist operand stack item");
-        let second = match first {
-            Item::Long(_) => operand_stack
+        if first.is_category_2() {
+            let second = first;
+            let first = operand_stack
                 .pop()
-                .expect("should exist operand stack item"),
-            _ => Item::Null,
-        };
-        (first, second)
+                .expect("should exist operand stack item");
+            (first, second)
+        } else {
+            (first, Item::Null)
+        }
     }
 
     fn get_last_stackframe(&mut self) -> &mut Stackframe {
@@ -931,6 +2125,58 @@ ${:?}",
             .expect("should exist stack_frame")
     }
 
+    // the elements are copied shallowly
+    fn clone_array(&mut self, string_map: &mut StringPool) {
+        let array_id = match self.get_operand_stack().last() {
+            Some(Item::Arrayref(array_id)) => *array_id,
+            item => unreachable!("should be Arrayref. actual: {:?}", item),
+        };
+        let size = self
+            .array_map
+            .get_size(&array_id)
+            .expect("should exist array in array_map");
+        if !self.reserve_heap(string_map, size) {
+            return;
+        }
+        self.get_operand_stack().pop();
+        let array = self
+            .array_map
+            .get(&array_id)
+            .expect("should exist array in array_map")
+            .clone();
+        let array_class_name = self
+            .array_map
+            .get_class_name(&array_id)
+            .expect("should exist class name of array");
+        let cloned_id = self.array_map.add(array, array_class_name, size);
+        self.get_operand_stack().push(Item::Arrayref(cloned_id));
+    }
+
+    // the return value is moved to the operand stack of the caller
+    fn return_value(&mut self, slot_count: usize) {
+        let operand_stack = self.get_operand_stack();
+        let length = operand_stack.len();
+        if length < slot_count {
+            unreachable!("should exist return value on operand_stack")
+        }
+        let mut items = operand_stack.split_off(length - slot_count);
+        operand_stack.clear();
+        if self.get_last_stackframe().discards_return_value {
+            return;
+        }
+        let return_slot = self.get_last_stackframe().return_slot;
+
+        let length = self.stack_frames.len();
+        if let Some(stackframe) = self.stack_frames.get_mut(length - 2) {
+            match return_slot {
+                Some(slot) => stackframe.operand_stack.stack[slot] = items.remove(0),
+                None => stackframe.operand_stack.stack.append(&mut items),
+            }
+        } else {
+            unreachable!("should exist over two stack_frame");
+        }
+    }
+
     fn get_operand_stack(&mut self) -> &mut Vec<Item> {
         let stackframe = self.get_last_stackframe();
         &mut stackframe.operand_stack.stack
@@ -941,104 +2187,865 @@ ${:?}",
         string_map: &mut StringPool,
         class_file: &Custom,
         class_name: usize,
-        name_and_type: &ConstantNameAndType,
+        method_name: usize,
+        method_descriptor: usize,
+    ) {
+        let class_name = match self.resolve_method_class(
+            string_map,
+            class_file,
+            class_name,
+            method_name,
+            method_descriptor,
+        ) {
+            Some(class_name) => class_name,
+            None => return,
+        };
+        let method = ResolvedMethod {
+            class_name,
+            method_name,
+            method_descriptor,
+            parameter_slot_count: get_parameter_slot_count(
+                &string_map.get_value(&method_descriptor),
+            ),
+            is_private: false,
+            is_array: false,
+        };
+        self.call_resolved_method(string_map, class_file, method, None);
+    }
+
+    // class_name is the class which declares the method
+    // the method index is looked up from the class if it is not cached
+    fn call_resolved_method(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        method: ResolvedMethod,
+        method_index: Option<usize>,
+    ) {
+        let ResolvedMethod {
+            class_name,
+            method_name,
+            method_descriptor,
+            ..
+        } = method;
+        if self.is_builtin_method(
+            class_name,
+            method_name,
+            BuitlInCodeType::JavaLangStringValueOf,
+        ) {
+            self.string_value_of(string_map, class_file, method_descriptor);
+            return;
+        }
+        if self.is_builtin_method(
+            class_name,
+            method_name,
+            BuitlInCodeType::JavaLangEnumValueOf,
+        ) {
+            self.enum_value_of(string_map);
+            return;
+        }
+
+        let class = self.load_class(string_map, class_name);
+        self.call_other_class_method(
+            string_map,
+            &class,
+            &class_file.cp_info,
+            &method,
+            method_index,
+        );
+    }
+
+    // for the builtin methods which are executed by Context
+    fn is_builtin_method(
+        &self,
+        class_name: usize,
+        method_name: usize,
+        code_type: BuitlInCodeType,
+    ) -> bool {
+        match self.class_map.get(&class_name) {
+            Some(JavaClass::BuiltIn(builtin)) => builtin
+                .methods
+                .get(&method_name)
+                .map_or(false, |method| method.code_type == code_type),
+            _ => false,
+        }
+    }
+
+    // the enum constant is searched from $VALUES which javac generates for every enum class
+    fn enum_value_of(&mut self, string_map: &mut StringPool) {
+        let operand_stack = self.get_operand_stack();
+        let (enum_class_name, name) = match (operand_stack.pop(), operand_stack.pop()) {
+            (Some(Item::String(name)), Some(Item::Classref(enum_class_name))) => {
+                (enum_class_name, name)
+            }
+            (Some(Item::Null), _) => {
+                self.throw_exception(
+                    string_map,
+                    "java/lang/NullPointerException",
+                    Some(String::from("Name is null")),
+                );
+                return;
+            }
+            items => unreachable!("should be Classref and String. actual: {:?}", items),
+        };
+        let values_name = string_map.insert(String::from("$VALUES"));
+        let slot = self
+            .get_field_layout(string_map, enum_class_name)
+            .get_static_slot(values_name)
+            .map(|(_, slot)| slot);
+        let array_id = match slot.and_then(|slot| self.static_fields.get(slot)) {
+            Some((Item::Arrayref(array_id), _)) => *array_id,
+            item => unreachable!("should exist $VALUES of enum. actual: {:?}", item),
+        };
+        let object_ids = match self.array_map.get(&array_id) {
+            Some(Array::Custom(items)) => items
+                .borrow()
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Objectref(object_id) => Some(*object_id),
+                    _ => None,
+                })
+                .collect::<Vec<usize>>(),
+            _ => unreachable!("should be array of enum constants"),
+        };
+
+        let name_value = string_map.get_value(&name);
+        let enum_constant = object_ids.into_iter().find(|object_id| {
+            match get_enum_field(&self.object_map, *object_id, ENUM_NAME_SLOT) {
+                Item::String(constant_name) => string_map.get_value(&constant_name) == name_value,
+                _ => false,
+            }
+        });
+        match enum_constant {
+            Some(object_id) => self.get_operand_stack().push(Item::Objectref(object_id)),
+            None => {
+                let message = format!(
+                    "No enum constant {}.{}",
+                    self.get_canonical_name(string_map, enum_class_name),
+                    name_value
+                );
+                self.throw_exception(
+                    string_map,
+                    "java/lang/IllegalArgumentException",
+                    Some(message),
+                );
+            }
+        }
+    }
+
+    // same as Class.getCanonicalName. e.g. Outer$Inner => Outer.Inner
+    fn get_canonical_name(&mut self, string_map: &mut StringPool, class_name: usize) -> String {
+        let outer_class = match self.load_class(string_map, class_name) {
+            JavaClass::Custom(custom) => custom.get_outer_class(),
+            JavaClass::BuiltIn(_) => None,
+        };
+        match outer_class {
+            Some((outer_class_name, simple_name)) => format!(
+                "{}.{}",
+                self.get_canonical_name(string_map, outer_class_name),
+                string_map.get_value(&simple_name)
+            ),
+            None => string_map.get_value(&class_name).replace("/", "."),
+        }
+    }
+
+    fn string_value_of(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        method_descriptor: usize,
     ) {
-        let method_name = class_file.cp_info.get_utf8(name_and_type.name_index);
-        let method_descriptor = class_file.cp_info.get_utf8(name_and_type.descriptor_index);
+        let method_descriptor = string_map.get_value(&method_descriptor);
+        let parameter_descriptor = get_parameter_descriptors(&method_descriptor)[0];
+        if !self.call_to_string(string_map, class_file, get_slot_count(parameter_descriptor)) {
+            return;
+        }
+        let operand_stack = self.get_operand_stack();
+        let (first, second) = match get_slot_count(parameter_descriptor) {
+            2 => {
+                let second = operand_stack.pop().expect("should exist argument");
+                (operand_stack.pop().expect("should exist argument"), second)
+            }
+            _ => (
+                operand_stack.pop().expect("should exist argument"),
+                Item::Null,
+            ),
+        };
+        let value = self.stringify_item(string_map, parameter_descriptor, first, second);
+        let string_id = string_map.insert(value);
+        let operand_stack = self.get_operand_stack();
+        operand_stack.push(Item::String(string_id));
+    }
 
-        if let Some(mut class) = self.class_map.remove(&class_name) {
-            self.call_other_class_method(
+    // the objects on the top slot_count items of the operand stack are replaced with the results
+    // of toString. returns false if toString is pushed on the frames or the exception is thrown.
+    // the instruction is executed again after toString returns
+    fn call_to_string(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        slot_count: usize,
+    ) -> bool {
+        let to_string_name = string_map.insert(String::from("toString"));
+        let to_string_descriptor = string_map.insert(String::from("()Ljava/lang/String;"));
+        let object_class_name = string_map.insert(String::from("java/lang/Object"));
+        let length = self.get_operand_stack().len();
+        for slot in length - slot_count..length {
+            let object_id = match self.get_operand_stack()[slot] {
+                Item::Objectref(object_id) => object_id,
+                _ => continue,
+            };
+            if self.lambda_map.contains_key(&object_id) {
+                continue;
+            }
+            let class_name = self
+                .object_map
+                .get(&object_id)
+                .expect("should exist object_ref in object_map")
+                .class_name_id;
+            let target_class_name = match self.find_method_class(
                 string_map,
-                &mut class,
-                &class_file.cp_info,
-                method_name,
-                method_descriptor,
-            );
-            self.class_map.insert(class.this_class_name(), class);
-        } else {
-            let new_class_file = self.create_custom_class(string_map, class_name);
-            let mut new_class_file = JavaClass::Custom(new_class_file);
+                class_file,
+                class_name,
+                to_string_name,
+                to_string_descriptor,
+            ) {
+                Some(target_class_name) if target_class_name != object_class_name => {
+                    target_class_name
+                }
+                _ => continue,
+            };
 
-            self.call_other_class_method(
+            let depth = self.stack_frames.len();
+            self.get_operand_stack().push(Item::Objectref(object_id));
+            self.call_method(
                 string_map,
-                &mut new_class_file,
-                &class_file.cp_info,
-                method_name,
-                method_descriptor,
+                class_file,
+                target_class_name,
+                to_string_name,
+                to_string_descriptor,
             );
-            self.class_map.insert(class_name, new_class_file);
+            if self.thrown_exception.is_some() {
+                return false;
+            }
+            if self.stack_frames.len() > depth {
+                self.get_last_stackframe().return_slot = Some(slot);
+                return false;
+            }
+            // the builtin method returns immediately. e.g. toString of Enum
+            let operand_stack = self.get_operand_stack();
+            operand_stack[slot] = operand_stack
+                .pop()
+                .expect("should exist result of toString");
+        }
+        true
+    }
+
+    // returns the object id. the fields are initialized by the layout of the class
+    fn create_object(&mut self, string_map: &mut StringPool, class_name: usize) -> usize {
+        let layout = self.get_field_layout(string_map, class_name);
+        let fields = RefCell::new(layout.initial_values.clone());
+        self.object_map.add(Objectref::new(class_name, fields))
+    }
+
+    fn invoke_dynamic(&mut self, string_map: &mut StringPool, class_file: &Custom, index: usize) {
+        let invoke_dynamic = class_file.cp_info.get_invoke_dynamic(index);
+        let name_and_type = class_file
+            .cp_info
+            .get_name_and_type(invoke_dynamic.name_and_type_index);
+        let name = class_file.cp_info.get_utf8(name_and_type.name_index);
+        let descriptor = class_file.cp_info.get_utf8(name_and_type.descriptor_index);
+        let bootstrap_method =
+            class_file.get_bootstrap_method(invoke_dynamic.bootstrap_method_attr_index);
+        let method_handle = class_file
+            .cp_info
+            .get_method_handle(bootstrap_method.bootstrap_method_ref);
+        let (class_name, method_name, _) =
+            self.get_related_method_info(class_file, method_handle.reference_index);
+        let arguments = &bootstrap_method.bootstrap_arguments;
+
+        // the bootstrap methods are not executed. the call sites are emulated natively
+        match (
+            string_map.get_value(&class_name).as_ref(),
+            string_map.get_value(&method_name).as_ref(),
+        ) {
+            ("java/lang/invoke/StringConcatFactory", "makeConcatWithConstants") => {
+                let recipe = string_map.get_value(&class_file.cp_info.get_string(arguments[0]));
+                let constants = arguments[1..]
+                    .iter()
+                    .map(|index| string_map.get_value(&class_file.cp_info.get_string(*index)))
+                    .collect();
+                self.make_concat(string_map, class_file, descriptor, recipe, constants);
+            }
+            ("java/lang/invoke/StringConcatFactory", "makeConcat") => {
+                let descriptor_value = string_map.get_value(&descriptor);
+                let recipe = "\u{1}".repeat(get_parameter_descriptors(&descriptor_value).len());
+                self.make_concat(string_map, class_file, descriptor, recipe, vec![]);
+            }
+            ("java/lang/invoke/LambdaMetafactory", "metafactory") => {
+                let implementation_method = class_file.cp_info.get_method_handle(arguments[1]);
+                let (class_name, method_name, method_descriptor) =
+                    self.get_related_method_info(class_file, implementation_method.reference_index);
+                let captured_slot_count =
+                    get_parameter_slot_count(&string_map.get_value(&descriptor));
+                let operand_stack = self.get_operand_stack();
+                let captured_items =
+                    operand_stack.split_off(operand_stack.len() - captured_slot_count);
+
+                // the lambda is an instance of the functional interface
+                let descriptor = string_map.get_value(&descriptor);
+                let return_descriptor = get_return_descriptor(&descriptor);
+                let interface_name = string_map
+                    .insert(return_descriptor[1..return_descriptor.len() - 1].to_string());
+                let object_ref = Objectref::new(interface_name, RefCell::new(vec![]));
+                let id = self.object_map.add(object_ref);
+                self.lambda_map.insert(
+                    id,
+                    Lambda {
+                        interface_method_name: name,
+                        reference_kind: implementation_method.reference_kind,
+                        class_name,
+                        method_name,
+                        method_descriptor,
+                        captured_items,
+                    },
+                );
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::Objectref(id));
+            }
+            (class_name, method_name) => unimplemented!(
+                "bootstrap method {}.{} is not supported",
+                class_name,
+                method_name
+            ),
+        };
+    }
+
+    // \u{1} in the recipe is replaced with an argument, and \u{2} is replaced with a constant
+    fn make_concat(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        descriptor: usize,
+        recipe: String,
+        constants: Vec<String>,
+    ) {
+        let descriptor = string_map.get_value(&descriptor);
+        let slot_count = get_parameter_slot_count(&descriptor);
+        if !self.call_to_string(string_map, class_file, slot_count) {
+            return;
+        }
+        let operand_stack = self.get_operand_stack();
+        let mut items = operand_stack
+            .split_off(operand_stack.len() - slot_count)
+            .into_iter();
+
+        let mut arguments = vec![];
+        for parameter_descriptor in get_parameter_descriptors(&descriptor) {
+            let first = items.next().expect("should exist argument of concat");
+            let second = match get_slot_count(parameter_descriptor) {
+                2 => items.next().expect("should exist second item of argument"),
+                _ => Item::Null,
+            };
+            arguments.push(self.stringify_item(string_map, parameter_descriptor, first, second));
+        }
+
+        let mut arguments = arguments.into_iter();
+        let mut constants = constants.into_iter();
+        let mut result = String::new();
+        for c in recipe.chars() {
+            match c {
+                '\u{1}' => result.push_str(&arguments.next().expect("should exist argument")),
+                '\u{2}' => result.push_str(&constants.next().expect("should exist constant")),
+                _ => result.push(c),
+            }
+        }
+        let string_id = string_map.insert(result);
+        let operand_stack = self.get_operand_stack();
+        operand_stack.push(Item::String(string_id));
+    }
+
+    // same as String.valueOf
+    fn stringify_item(
+        &mut self,
+        string_map: &mut StringPool,
+        descriptor: &str,
+        first: Item,
+        second: Item,
+    ) -> String {
+        match (&descriptor[0..1], first, second) {
+            (_, Item::Null, _) => String::from("null"),
+            (_, Item::String(id), _) => string_map.get_value(&id),
+            ("Z", Item::Int(value), _) => (value != 0).to_string(),
+            // a lone surrogate is not a char of Rust
+            ("C", Item::Int(value), _) => std::char::from_u32(value as u32)
+                .unwrap_or('\u{FFFD}')
+                .to_string(),
+            (_, Item::Int(value), _) => value.to_string(),
+            (_, Item::Boolean(value), _) => value.to_string(),
+            (_, Item::Float(value), _) => format_floating_point(value),
+            (_, Item::Long(value), _) => value.to_string(),
+            (_, Item::Double(value), _) => format_floating_point(value),
+            // toString is already invoked if the class overrides it
+            (_, Item::Objectref(object_id), _) => {
+                let class_name = self
+                    .object_map
+                    .get(&object_id)
+                    .expect("should exist object_ref in object_map")
+                    .class_name_id;
+                format!(
+                    "{}@{:x}",
+                    string_map.get_value(&class_name).replace("/", "."),
+                    object_id
+                )
+            }
+            (descriptor, first, second) => unimplemented!(
+                "stringify {} is not supported. {:?} {:?}",
+                descriptor,
+                first,
+                second
+            ),
         }
     }
 
-    fn initilize_class_static_info(
+    // the arguments are passed to the implementation method after the captured items
+    fn call_lambda(
         &mut self,
         string_map: &mut StringPool,
-        this_class_name_id: usize,
-        class_name_id: usize,
+        class_file: &Custom,
+        interface_method_descriptor: usize,
     ) {
-        let this_class_name = string_map.get_value(&this_class_name_id);
-        let class_name = string_map.get_value(&class_name_id);
-        if this_class_name != class_name && self.class_map.get_mut(&class_name_id).is_none() {
-            let new_class_file = self.create_custom_class(string_map, class_name_id);
-            if let Some(code) = new_class_file.get_clinit_code() {
-                self.call_custom_class_method(string_map, &new_class_file, code);
+        let interface_method_descriptor = string_map.get_value(&interface_method_descriptor);
+        let argument_slot_count = get_parameter_slot_count(&interface_method_descriptor);
+        let operand_stack = self.get_operand_stack();
+        let object_id = match operand_stack[operand_stack.len() - argument_slot_count - 1] {
+            Item::Objectref(object_id) => object_id,
+            ref item @ _ => unreachable!("should be Objectref. actual: {:?}", item),
+        };
+        let lambda = self
+            .lambda_map
+            .get(&object_id)
+            .expect("should exist lambda in lambda_map");
+        let (reference_kind, class_name) = (lambda.reference_kind, lambda.class_name);
+        // the arguments stay on the operand stack until <clinit> returns
+        // REF_invokeStatic, REF_newInvokeSpecial
+        if (reference_kind == 6 || reference_kind == 8)
+            && !self.initialize_class(string_map, class_name)
+        {
+            return;
+        }
+
+        let operand_stack = self.get_operand_stack();
+        let mut arguments = operand_stack.split_off(operand_stack.len() - argument_slot_count);
+        operand_stack.pop();
+        let lambda = self
+            .lambda_map
+            .get(&object_id)
+            .expect("should exist lambda in lambda_map");
+        let (method_name, method_descriptor) = (lambda.method_name, lambda.method_descriptor);
+        let mut items = lambda.captured_items.clone();
+        items.append(&mut arguments);
+
+        let method_descriptor_value = string_map.get_value(&method_descriptor);
+        let parameter_slot_count = get_parameter_slot_count(&method_descriptor_value);
+        let depth = self.stack_frames.len();
+        let return_slot_count = match reference_kind {
+            // REF_invokeStatic
+            6 => {
+                self.get_operand_stack().append(&mut items);
+                self.call_method(
+                    string_map,
+                    class_file,
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                );
+                get_slot_count(get_return_descriptor(&method_descriptor_value))
+            }
+            // REF_invokeVirtual, REF_invokeInterface
+            5 | 9 => {
+                self.get_operand_stack().append(&mut items);
+                let receiver_class_name = self
+                    .get_receiver_class_name(parameter_slot_count)
+                    .unwrap_or(class_name);
+                self.call_method(
+                    string_map,
+                    class_file,
+                    receiver_class_name,
+                    method_name,
+                    method_descriptor,
+                );
+                get_slot_count(get_return_descriptor(&method_descriptor_value))
+            }
+            // REF_invokeSpecial
+            7 => {
+                self.get_operand_stack().append(&mut items);
+                self.call_method(
+                    string_map,
+                    class_file,
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                );
+                get_slot_count(get_return_descriptor(&method_descriptor_value))
+            }
+            // REF_newInvokeSpecial. e.g. Foo::new
+            8 => {
+                let id = self.create_object(string_map, class_name);
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::Objectref(id));
+                operand_stack.push(Item::Objectref(id));
+                operand_stack.append(&mut items);
+                self.call_method(
+                    string_map,
+                    class_file,
+                    class_name,
+                    method_name,
+                    method_descriptor,
+                );
+                1
+            }
+            _ => unimplemented!("reference_kind {} is not supported", reference_kind),
+        };
+
+        // e.g. the result of the implementation method is discarded for Runnable
+        if self.thrown_exception.is_none()
+            && get_slot_count(get_return_descriptor(&interface_method_descriptor)) == 0
+        {
+            // the created object of REF_newInvokeSpecial is already on the operand stack
+            if reference_kind != 8 && self.stack_frames.len() > depth {
+                self.get_last_stackframe().discards_return_value = true;
+            } else {
+                let operand_stack = &mut self.stack_frames[depth - 1].operand_stack.stack;
+                for _ in 0..return_slot_count {
+                    operand_stack.pop();
+                }
+            }
+        }
+    }
+
+    // JVMS 5.5. the super class is initialized before the class.
+    // returns false if <clinit> is pushed on the frames or the exception is thrown.
+    // the instruction is executed again after <clinit> returns
+    fn initialize_class(&mut self, string_map: &mut StringPool, class_name: usize) -> bool {
+        match self.class_init_states.get(&class_name) {
+            // a recursive request while running <clinit> is ignored
+            Some(ClassInitState::BeingInitialized) | Some(ClassInitState::Initialized) => {
+                return true
+            }
+            Some(ClassInitState::Erroneous) => {
+                let message = format!(
+                    "Could not initialize class {}",
+                    string_map.get_value(&class_name).replace("/", ".")
+                );
+                self.throw_exception(string_map, "java/lang/NoClassDefFoundError", Some(message));
+                return false;
+            }
+            None => {}
+        };
+        let class_file = match self.load_class(string_map, class_name) {
+            JavaClass::Custom(class_file) => class_file,
+            JavaClass::BuiltIn(_) => {
+                self.class_init_states
+                    .insert(class_name, ClassInitState::Initialized);
+                return true;
+            }
+        };
+
+        let method_index = match class_file.get_clinit_method_index() {
+            Some(method_index) => method_index,
+            None => {
+                self.class_init_states
+                    .insert(class_name, ClassInitState::BeingInitialized);
+                let initialized = match class_file.super_class_name() {
+                    Some(super_class_name) => self.initialize_class(string_map, super_class_name),
+                    None => true,
+                };
+                if initialized {
+                    self.class_init_states
+                        .insert(class_name, ClassInitState::Initialized);
+                } else if self.thrown_exception.is_some() {
+                    self.class_init_states
+                        .insert(class_name, ClassInitState::Erroneous);
+                } else {
+                    // the state is decided when the instruction is executed again
+                    self.class_init_states.remove(&class_name);
+                }
+                return initialized;
+            }
+        };
+        if self.stack_frames.len() >= self.max_stack_depth {
+            self.throw_exception(string_map, "java/lang/StackOverflowError", None);
+            return false;
+        }
+        self.class_init_states
+            .insert(class_name, ClassInitState::BeingInitialized);
+
+        let code = class_file.methods[method_index]
+            .extract_code()
+            .expect("should exist code in <clinit>");
+        let mut stack_frame = Stackframe::new(code.max_locals as usize, code.max_stack as usize);
+        stack_frame.method = Some((class_file.clone(), method_index));
+        stack_frame.initializing_class = Some(class_name);
+        self.stack_frames.push(stack_frame);
+
+        // <clinit> of the super class is pushed above and runs first
+        if let Some(super_class_name) = class_file.super_class_name() {
+            if !self.initialize_class(string_map, super_class_name)
+                && self.thrown_exception.is_some()
+                && self.get_last_stackframe().initializing_class == Some(class_name)
+            {
+                self.stack_frames.pop();
+                self.class_init_states
+                    .insert(class_name, ClassInitState::Erroneous);
+            }
+        }
+        false
+    }
+
+    // the exception which isn't Error is wrapped by ExceptionInInitializerError
+    fn wrap_initializer_exception(&mut self, string_map: &mut StringPool, class_file: &Custom) {
+        let object_id = match self.thrown_exception {
+            Some(object_id) => object_id,
+            None => return,
+        };
+        let exception_class_name = self
+            .object_map
+            .get(&object_id)
+            .expect("should exist exception in object_map")
+            .class_name_id;
+        let error_class_name = string_map.insert(String::from("java/lang/Error"));
+        if self.is_subclass_of(
+            string_map,
+            class_file,
+            exception_class_name,
+            error_class_name,
+        ) {
+            return;
+        }
+
+        self.throw_exception(string_map, "java/lang/ExceptionInInitializerError", None);
+        let error_id = self
+            .thrown_exception
+            .expect("should exist ExceptionInInitializerError");
+        self.object_map
+            .get(&error_id)
+            .expect("should exist exception in object_map")
+            .fields
+            .borrow_mut()[THROWABLE_CAUSE_SLOT] = (Item::Objectref(object_id), Item::Null);
+    }
+
+    // the roots are the frames, the static fields and the exception which is being thrown.
+    // every item is on them because the methods are not run by the Rust stack
+    fn collect_garbage(&mut self) {
+        let mut roots = vec![];
+        for stackframe in self.stack_frames.iter() {
+            roots.extend(stackframe.local_variables.iter().cloned());
+            roots.extend(stackframe.operand_stack.stack.iter().cloned());
+        }
+        roots.extend(self.static_fields.iter().map(|(first, _)| first.clone()));
+        if let Some(object_id) = self.thrown_exception {
+            roots.push(Item::Objectref(object_id));
+        }
+        self.gc.collect(
+            roots,
+            &mut self.object_map,
+            &mut self.array_map,
+            &mut self.lambda_map,
+        );
+    }
+
+    // the heap is collected before throwing OutOfMemoryError.
+    // returns false if OutOfMemoryError is thrown
+    fn reserve_heap(&mut self, string_map: &mut StringPool, size: usize) -> bool {
+        if self
+            .gc
+            .can_allocate(&self.object_map, &self.array_map, size)
+        {
+            return true;
+        }
+        self.collect_garbage();
+        if self
+            .gc
+            .can_allocate(&self.object_map, &self.array_map, size)
+        {
+            return true;
+        }
+        self.throw_exception(
+            string_map,
+            "java/lang/OutOfMemoryError",
+            Some(String::from("Java heap space")),
+        );
+        false
+    }
+
+    // each class is parsed and linked only once
+    fn load_class(&mut self, string_map: &mut StringPool, class_name: usize) -> JavaClass {
+        if let Some(class) = self.class_map.get(&class_name) {
+            return class.clone();
+        }
+        let new_class_file = self.load_class_file(string_map, class_name);
+        let class = JavaClass::Custom(Rc::new(new_class_file));
+        self.class_map.insert(class_name, class.clone());
+        self.link_class(string_map, &class);
+        class
+    }
+
+    // the builtin classes are linked when the layout is used first
+    fn get_field_layout(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+    ) -> Rc<FieldLayout> {
+        if let Some(layout) = self.field_layouts.get(&class_name) {
+            return layout.clone();
+        }
+        let class = self.load_class(string_map, class_name);
+        match self.field_layouts.get(&class_name) {
+            Some(layout) => layout.clone(),
+            None => self.link_class(string_map, &class),
+        }
+    }
+
+    // the fields are added to the layout of the super class.
+    // the static fields are prepared here. static final constants are set instead of <clinit>
+    fn link_class(&mut self, string_map: &mut StringPool, class: &JavaClass) -> Rc<FieldLayout> {
+        let class_name = class.this_class_name();
+        let (super_class_name, interface_names) = match class {
+            JavaClass::BuiltIn(builtin) => (builtin.super_class_name, builtin.interfaces.clone()),
+            JavaClass::Custom(custom) => (custom.super_class_name(), custom.get_interface_names()),
+        };
+        let mut layout = match super_class_name {
+            Some(super_class_name) => {
+                (*self.get_field_layout(string_map, super_class_name)).clone()
             }
+            None => FieldLayout::default(),
+        };
+        // the static fields of the superinterfaces are found before the ones of the super class.
+        // the first interface is searched first. JVMS 5.4.3.2
+        for interface_name in interface_names.into_iter().rev() {
+            let interface_layout = self.get_field_layout(string_map, interface_name);
+            layout.add_interface_static_fields(&interface_layout);
+        }
 
-            self.class_map
-                .insert(class_name_id, JavaClass::Custom(new_class_file));
+        match class {
+            JavaClass::BuiltIn(builtin) => {
+                for field_name in builtin.fields.iter() {
+                    layout.add_instance_field(*field_name, (Item::Null, Item::Null));
+                }
+                for (field_name, value) in builtin.static_fields.iter() {
+                    layout.add_static_field(class_name, *field_name, self.static_fields.len());
+                    self.static_fields.push(value.clone());
+                }
+            }
+            JavaClass::Custom(custom) => {
+                for field in custom.fields.iter() {
+                    let field_name = custom.cp_info.get_utf8(field.name_index);
+                    let descriptor = custom.get_descriptor(string_map, field.descriptor_index);
+                    if !field.is_static() {
+                        layout
+                            .add_instance_field(field_name, create_uninitialized_item(&descriptor));
+                        continue;
+                    }
+                    let value = match field.get_constant_value_index() {
+                        Some(index) => custom.cp_info.get_constant_value(index),
+                        None => create_uninitialized_item(&descriptor),
+                    };
+                    layout.add_static_field(class_name, field_name, self.static_fields.len());
+                    self.static_fields.push(value);
+                }
+            }
         }
+
+        let layout = Rc::new(layout);
+        self.field_layouts.insert(class_name, layout.clone());
+        layout
     }
 
-    fn create_custom_class(&mut self, string_map: &mut StringPool, class_name: usize) -> Custom {
+    fn load_class_file(&self, string_map: &mut StringPool, class_name: usize) -> Custom {
         let class_name = string_map.get_value(&class_name);
         let class_name = class_name + ".class";
         let class_path = Path::new(self.root_path).join(&class_name);
         let buffer = get_file_content(&class_path.to_str().unwrap());
         let (new_class_file, _pc_count) = Custom::new(string_map, &buffer, 0);
-        // TBD should be set initial value
-        set_static_fields(string_map, &new_class_file, &mut self.static_fields);
         new_class_file
     }
 
     fn call_other_class_method(
         &mut self,
         string_map: &mut StringPool,
-        class_file: &mut JavaClass,
+        class_file: &JavaClass,
         caller_cp_info: &ConstantPool,
-        method_name: usize,
-        method_descriptor: usize,
+        resolved_method: &ResolvedMethod,
+        method_index: Option<usize>,
     ) {
+        let method_name = resolved_method.method_name;
+        let method_descriptor = resolved_method.method_descriptor;
         match class_file {
-            JavaClass::BuiltIn(ref mut builtin_class) => {
-                let method = builtin_class.methods.get_mut(&method_name).expect(&format!(
+            JavaClass::BuiltIn(builtin_class) => {
+                let method = builtin_class.methods.get(&method_name).expect(&format!(
                     "{} is not found in {}",
                     method_name, builtin_class.class_name
                 ));
-                let parameter_length = method.parameter_length(string_map, method_descriptor);
-                let stack_frame = self.create_new_stack_frame(parameter_length);
+                let receiver_slot_count = if method.is_static() { 0 } else { 1 };
+                let argument_slot_count =
+                    receiver_slot_count + resolved_method.parameter_slot_count;
+                let stack_frame =
+                    self.create_new_stack_frame(argument_slot_count, argument_slot_count, 0);
                 self.stack_frames.push(stack_frame);
-                method.execute(string_map, &caller_cp_info, &mut self.stack_frames);
+                let mut return_items = method.execute(
+                    string_map,
+                    &caller_cp_info,
+                    &mut self.object_map,
+                    &mut self.stack_frames,
+                );
+                self.get_operand_stack().append(&mut return_items);
             }
-            JavaClass::Custom(ref custom_class) => {
-                if let Some(method_code) =
-                    custom_class.get_method_code_by_string(method_name, method_descriptor)
-                {
-                    self.call_custom_class_method(string_map, custom_class, &method_code);
+            JavaClass::Custom(custom_class) => {
+                let method_index = method_index
+                    .or_else(|| {
+                        custom_class.get_method_index_by_string(method_name, method_descriptor)
+                    })
+                    .expect("should exist method in class");
+                let method = &custom_class.methods[method_index];
+                if method.extract_code().is_some() {
+                    // the receiver is passed as the first local variable
+                    let receiver_slot_count = if method.is_static() { 0 } else { 1 };
+                    let argument_slot_count =
+                        receiver_slot_count + resolved_method.parameter_slot_count;
+                    self.call_custom_class_method(
+                        string_map,
+                        custom_class,
+                        method_index,
+                        argument_slot_count,
+                    );
                 }
             }
         }
     }
 
+    // the frame is run by run_frames after the invoking instruction
     fn call_custom_class_method(
         &mut self,
         string_map: &mut StringPool,
-        class: &Custom,
-        code: &Code,
+        class: &Rc<Custom>,
+        method_index: usize,
+        argument_slot_count: usize,
     ) {
-        let local_variable_length = code.max_locals as usize;
-        let stack_frame = self.create_new_stack_frame(local_variable_length);
+        if self.stack_frames.len() >= self.max_stack_depth {
+            self.throw_exception(string_map, "java/lang/StackOverflowError", None);
+            return;
+        }
+        let code = class.methods[method_index]
+            .extract_code()
+            .expect("should exist code in method");
+        let mut stack_frame = self.create_new_stack_frame(
+            argument_slot_count,
+            code.max_locals as usize,
+            code.max_stack as usize,
+        );
+        stack_frame.method = Some((class.clone(), method_index));
         self.stack_frames.push(stack_frame);
-        self.run_method(string_map, class, code);
     }
 
     fn load_n(&mut self, index: usize) {
@@ -1074,104 +3081,112 @@ ${:?}",
         });
 
         for (index, item) in item_vec.into_iter() {
-            if stackframe.local_variables.get(index).is_some() {
-                stackframe.local_variables[index] = Item::from(item);
-            } else {
-                stackframe.local_variables.insert(index, Item::from(item));
-            }
+            stackframe.local_variables[index] = Item::from(item);
         }
     }
 
-    // (class_name, field_name)
-    fn get_class_and_field_name(&mut self, class_file: &Custom, index: usize) -> (usize, usize) {
-        let field_ref = class_file.cp_info.get_field_ref(index);
-        let class_ref = class_file.cp_info.get_class_ref(field_ref.class_index);
-        let name_and_type = class_file
-            .cp_info
-            .get_name_and_type(field_ref.name_and_type_index);
+    // (class_name, method_name, method_descriptor)
+    fn get_related_method_info(
+        &mut self,
+        class_file: &Custom,
+        index: usize,
+    ) -> (usize, usize, usize) {
+        let (class_index, name_and_type_index) = class_file.cp_info.get_method_ref_indexes(index);
+        let class_name = class_file.cp_info.get_class_ref_name(class_index);
+        let name_and_type = class_file.cp_info.get_name_and_type(name_and_type_index);
         (
-            class_file.cp_info.get_utf8(class_ref.name_index),
+            class_name,
             class_file.cp_info.get_utf8(name_and_type.name_index),
+            class_file.cp_info.get_utf8(name_and_type.descriptor_index),
         )
     }
 
-    fn get_related_method_info<'b>(
+    // the arguments are moved from the operand stack of the caller to the local variables
+    fn create_new_stack_frame(
         &mut self,
-        class_file: &'b Custom,
-        index: usize,
-    ) -> (usize, &'b ConstantNameAndType) {
-        let method_ref = class_file.cp_info.get_method_ref(index);
-        let class_ref = class_file.cp_info.get_class_ref(method_ref.class_index);
-        let name_and_type = class_file
-            .cp_info
-            .get_name_and_type(method_ref.name_and_type_index);
-        let class_name = class_file.cp_info.get_utf8(class_ref.name_index);
-        (class_name, name_and_type)
-    }
-
-    fn create_new_stack_frame(&mut self, local_variable_length: usize) -> Stackframe {
-        let mut new_stack_frame = Stackframe::new(local_variable_length);
-        let stackframe = self.get_last_stackframe();
-
-        // TBD need to fix this
-        let mut variables: Vec<_> = stackframe
-            .operand_stack
-            .stack
-            .iter()
-            .rev()
-            .map(|operand_item| Item::from(operand_item.clone()))
-            .collect();
-        let mut variables = variables.drain(0..local_variable_length).rev().collect();
-        new_stack_frame.local_variables.append(&mut variables);
-
-        // TBD need to fix this
-        for _ in 0..local_variable_length {
-            let _ = stackframe.operand_stack.stack.pop();
+        argument_slot_count: usize,
+        max_locals: usize,
+        max_stack: usize,
+    ) -> Stackframe {
+        let mut new_stack_frame = Stackframe::new(max_locals, max_stack);
+        let operand_stack = self.get_operand_stack();
+        let arguments = operand_stack.split_off(operand_stack.len() - argument_slot_count);
+        for (index, argument) in arguments.into_iter().enumerate() {
+            new_stack_frame.local_variables[index] = Item::from(argument);
         }
-
         new_stack_frame
     }
 }
 
-pub fn set_static_fields(
-    string_map: &mut StringPool,
-    class: &Custom,
-    static_fields: &mut StaticFields,
-) {
-    for field in class.fields.iter() {
-        let field_name = class.cp_info.get_utf8(field.name_index);
-        let value =
-            create_uninitialized_item(&class.get_descriptor(string_map, field.descriptor_index));
-        static_fields.insert((class.this_class_name(), field_name), value);
+// the leaves are charged the element size of the array type
+fn get_multi_dimentions_array_size(counts: &[usize], leaf_element_size: usize) -> usize {
+    let mut size: usize = 0;
+    let mut array_count: usize = 1;
+    for (dimention, count) in counts.iter().enumerate() {
+        let element_size = if dimention + 1 == counts.len() {
+            leaf_element_size
+        } else {
+            ARRAY_ELEMENT_SIZE
+        };
+        size =
+            size.saturating_add(array_count.saturating_mul(get_array_size(*count, element_size)));
+        array_count = array_count.saturating_mul(*count);
     }
+    size
 }
 
-pub fn setup_static_fields(string_map: &mut StringPool, class_map: &ClassMap) -> StaticFields {
-    let mut static_fields = HashMap::new();
-    for key in class_map.keys() {
-        if let Some(JavaClass::Custom(class)) = class_map.get(key) {
-            set_static_fields(string_map, &class, &mut static_fields);
-        }
+// e.g. java/lang/String => [Ljava/lang/String;, [I => [[I
+fn to_array_class_name(class_name: &str) -> String {
+    if class_name.starts_with('[') {
+        format!("[{}", class_name)
+    } else {
+        format!("[L{};", class_name)
     }
+}
 
-    let class_name_id = string_map.insert(String::from("java/lang/System"));
-    let field_name_id = string_map.insert(String::from("out"));
-    let class_ref_id = string_map.insert(String::from("java/io/PrintStream"));
-
-    static_fields.insert(
-        (class_name_id, field_name_id),
-        (Item::Classref(class_ref_id), Item::Null),
-    );
+// None for primitive types. e.g. Ljava/lang/String; => java/lang/String, [I => [I
+fn to_component_class_name(descriptor: &str) -> Option<String> {
+    match &descriptor[0..1] {
+        "L" => Some(descriptor[1..descriptor.len() - 1].to_string()),
+        "[" => Some(descriptor.to_string()),
+        _ => None,
+    }
+}
 
-    static_fields
+// the value is truncated to the element type. the elements of boolean array are Item::Boolean
+fn truncate_array_item(
+    instruction: &Instruction,
+    current_item: &(Item, Item),
+    values: (Item, Item),
+) -> (Item, Item) {
+    match (instruction, current_item, values) {
+        (Instruction::Bastore, (Item::Boolean(_), _), (Item::Int(value), _)) => {
+            (Item::Boolean(value & 1 == 1), Item::Null)
+        }
+        (Instruction::Bastore, _, (Item::Int(value), _)) => {
+            (Item::Int(value as i8 as i32), Item::Null)
+        }
+        (Instruction::Castore, _, (Item::Int(value), _)) => {
+            (Item::Int(value as u16 as i32), Item::Null)
+        }
+        (Instruction::Sastore, _, (Item::Int(value), _)) => {
+            (Item::Int(value as i16 as i32), Item::Null)
+        }
+        (_, _, values) => values,
+    }
 }
 
-// TBD need to create system to express uninitialized value
+// boolean, byte, char and short are held as int like the operand stack
 pub fn create_uninitialized_item(descriptor: &FieldDescriptor) -> (Item, Item) {
     match descriptor {
-        FieldDescriptor::BaseType(BaseType::I) => (Item::Int(0), Item::Null),
+        FieldDescriptor::BaseType(BaseType::B)
+        | FieldDescriptor::BaseType(BaseType::C)
+        | FieldDescriptor::BaseType(BaseType::I)
+        | FieldDescriptor::BaseType(BaseType::S)
+        | FieldDescriptor::BaseType(BaseType::Z) => (Item::Int(0), Item::Null),
         FieldDescriptor::BaseType(BaseType::J) => (Item::Long(0), Item::Long(0)),
-        FieldDescriptor::BaseType(BaseType::Z) => (Item::Boolean(true), Item::Null),
-        _ => unimplemented!("should implement"),
+        FieldDescriptor::BaseType(BaseType::F) => (Item::Float(0.0), Item::Null),
+        FieldDescriptor::BaseType(BaseType::D) => (Item::Double(0.0), Item::Double(0.0)),
+        FieldDescriptor::ObjectType(_) | FieldDescriptor::ArrayType(_) => (Item::Null, Item::Null),
     }
 }
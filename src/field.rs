@@ -1,4 +1,6 @@
 use crate::attribute::defs::Attribute;
+use crate::constant::ConstantPool;
+use crate::string_pool::StringPool;
 use crate::utils::extract_x_byte_as_usize;
 use std::fmt;
 
@@ -12,13 +14,25 @@ pub struct Field {
 }
 
 impl Field {
-    pub fn new(inputs: &[u8], index: usize) -> (Field, usize) {
+    pub fn new(
+        string_pool: &mut StringPool,
+        constant_pool: &ConstantPool,
+        inputs: &[u8],
+        index: usize,
+    ) -> (Field, usize) {
         let (access_flags, index) = extract_x_byte_as_usize(inputs, index, 2);
         let access_flags = extract_access_flags(access_flags);
 
         let (name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
         let (descriptor_index, index) = extract_x_byte_as_usize(inputs, index, 2);
-        let (attributes_count, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (attributes_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut attribute_info = Vec::with_capacity(attributes_count);
+        for _ in 0..attributes_count {
+            let (attribute, updated_index) =
+                Attribute::new(string_pool, constant_pool, inputs, index);
+            index = updated_index;
+            attribute_info.push(attribute);
+        }
 
         (
             Field {
@@ -26,11 +40,30 @@ impl Field {
                 name_index,
                 descriptor_index,
                 attributes_count,
-                attribute_info: vec![],
+                attribute_info,
             },
             index,
         )
     }
+
+    pub fn is_static(&self) -> bool {
+        self.access_flags
+            .0
+            .iter()
+            .any(|flag| *flag == FieldAccessFlag::AccStatic)
+    }
+
+    // the index of constant_pool. it is only used for static fields
+    pub fn get_constant_value_index(&self) -> Option<usize> {
+        self.attribute_info
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::ConstantValue(constant_value) => {
+                    Some(constant_value.constantvalue_index)
+                }
+                _ => None,
+            })
+    }
 }
 
 impl fmt::Display for Field {
@@ -86,13 +119,66 @@ impl From<&str> for FieldDescriptor {
             "J" => FieldDescriptor::BaseType(BaseType::J),
             "S" => FieldDescriptor::BaseType(BaseType::S),
             "Z" => FieldDescriptor::BaseType(BaseType::Z),
-            "L" => FieldDescriptor::ObjectType(input[1..].to_string()),
-            "[" => unimplemented!("need to implement for Array"),
+            "L" => FieldDescriptor::ObjectType(input[1..].trim_end_matches(';').to_string()),
+            "[" => FieldDescriptor::ArrayType(Box::new(FieldDescriptor::from(&input[1..]))),
             _ => panic!("failed to convert {} to FieldDescriptor", input),
         }
     }
 }
 
+// long and double use two slots. e.g. (IJLjava/lang/String;)V => 4
+pub fn get_parameter_slot_count(method_descriptor: &str) -> usize {
+    get_parameter_descriptors(method_descriptor)
+        .iter()
+        .map(|descriptor| get_slot_count(descriptor))
+        .sum()
+}
+
+// e.g. J => 2, V => 0
+pub fn get_slot_count(field_descriptor: &str) -> usize {
+    match &field_descriptor[0..1] {
+        "J" | "D" => 2,
+        "V" => 0,
+        _ => 1,
+    }
+}
+
+// e.g. (I[JLjava/lang/String;)V => ["I", "[J", "Ljava/lang/String;"]
+pub fn get_parameter_descriptors(method_descriptor: &str) -> Vec<&str> {
+    let end = method_descriptor
+        .find(')')
+        .expect("should exist ) in method descriptor");
+    let parameters = &method_descriptor[1..end];
+    let mut descriptors = vec![];
+    let mut start = 0;
+    let mut chars = parameters.char_indices();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '[' => continue,
+            'L' => {
+                while let Some((_, c)) = chars.next() {
+                    if c == ';' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+        let next = chars.clone().next().map_or(parameters.len(), |(i, _)| i);
+        descriptors.push(&parameters[start..next]);
+        start = next;
+    }
+    descriptors
+}
+
+// e.g. (I)Ljava/lang/String; => Ljava/lang/String;
+pub fn get_return_descriptor(method_descriptor: &str) -> &str {
+    let end = method_descriptor
+        .find(')')
+        .expect("should exist ) in method descriptor");
+    &method_descriptor[end + 1..]
+}
+
 fn extract_access_flags(num: usize) -> FieldAccessFlags {
     let mut access_flags = vec![];
     crate::add_flags!(&mut access_flags, num, FieldAccessFlag::AccPublic);
@@ -109,7 +195,7 @@ fn extract_access_flags(num: usize) -> FieldAccessFlags {
 }
 
 #[derive(Debug)]
-pub struct FieldAccessFlags(Vec<FieldAccessFlag>);
+pub struct FieldAccessFlags(pub Vec<FieldAccessFlag>);
 impl fmt::Display for FieldAccessFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut result = Vec::with_capacity(self.0.len());
@@ -120,7 +206,7 @@ impl fmt::Display for FieldAccessFlags {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum FieldAccessFlag {
     Unknown = 0x0000,
     AccPublic = 0x0001,
@@ -168,3 +254,23 @@ impl From<usize> for FieldAccessFlag {
         }
     }
 }
+
+#[test]
+fn test_get_parameter_slot_count() {
+    assert_eq!(get_parameter_slot_count("()V"), 0);
+    assert_eq!(get_parameter_slot_count("(IJLjava/lang/String;)V"), 4);
+    assert_eq!(get_parameter_slot_count("([[Ljava/lang/String;D[I)I"), 4);
+}
+
+#[test]
+fn test_get_parameter_descriptors() {
+    assert_eq!(get_parameter_descriptors("()V"), Vec::<&str>::new());
+    assert_eq!(
+        get_parameter_descriptors("(I[JLjava/lang/String;[[Ljava/lang/Object;C)V"),
+        vec!["I", "[J", "Ljava/lang/String;", "[[Ljava/lang/Object;", "C"]
+    );
+    assert_eq!(
+        get_return_descriptor("(I)Ljava/lang/String;"),
+        "Ljava/lang/String;"
+    );
+}
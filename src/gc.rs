@@ -0,0 +1,141 @@
+use crate::array::{Array, ArrayMap};
+use crate::object::{Lambda, ObjectMap};
+use crate::operand::Item;
+
+use std::collections::{HashMap, HashSet};
+
+// the first collection runs when the heap reaches it
+pub const INITIAL_GC_THRESHOLD: usize = 1024 * 1024;
+
+// mark and sweep for ObjectMap and ArrayMap. the interned strings live in StringPool
+// and never refer to objects and arrays, so every string is alive and is not traced
+#[derive(Debug)]
+pub struct GarbageCollector {
+    // -Xmx. OutOfMemoryError is thrown when the live objects and arrays exceed it
+    pub max_heap_size: usize,
+    // the heap is collected when its size exceeds it
+    pub threshold: usize,
+}
+
+impl GarbageCollector {
+    pub fn new(max_heap_size: usize) -> Self {
+        GarbageCollector {
+            max_heap_size,
+            threshold: INITIAL_GC_THRESHOLD.min(max_heap_size),
+        }
+    }
+
+    pub fn should_collect(&self, object_map: &ObjectMap, array_map: &ArrayMap) -> bool {
+        get_heap_size(object_map, array_map) > self.threshold
+    }
+
+    pub fn can_allocate(&self, object_map: &ObjectMap, array_map: &ArrayMap, size: usize) -> bool {
+        get_heap_size(object_map, array_map).saturating_add(size) <= self.max_heap_size
+    }
+
+    // the next collection runs when the heap is twice as large as the live objects and arrays
+    pub fn collect(
+        &mut self,
+        roots: Vec<Item>,
+        object_map: &mut ObjectMap,
+        array_map: &mut ArrayMap,
+        lambda_map: &mut HashMap<usize, Lambda>,
+    ) {
+        let (object_ids, array_ids) = mark(roots, object_map, array_map, lambda_map);
+        object_map.retain(|id| object_ids.contains(id));
+        array_map.retain(|id| array_ids.contains(id));
+        lambda_map.retain(|id, _| object_ids.contains(id));
+
+        let live_size = get_heap_size(object_map, array_map);
+        self.threshold = live_size
+            .saturating_mul(2)
+            .max(INITIAL_GC_THRESHOLD)
+            .min(self.max_heap_size);
+    }
+}
+
+pub fn get_heap_size(object_map: &ObjectMap, array_map: &ArrayMap) -> usize {
+    object_map.size + array_map.size
+}
+
+// returns the ids of the reachable objects and arrays
+fn mark(
+    roots: Vec<Item>,
+    object_map: &ObjectMap,
+    array_map: &ArrayMap,
+    lambda_map: &HashMap<usize, Lambda>,
+) -> (HashSet<usize>, HashSet<usize>) {
+    let mut object_ids = HashSet::new();
+    let mut array_ids = HashSet::new();
+    let mut items = roots;
+    while let Some(item) = items.pop() {
+        match item {
+            Item::Objectref(object_id) if object_ids.insert(object_id) => {
+                if let Some(object_ref) = object_map.get(&object_id) {
+                    let fields = object_ref.fields.borrow();
+                    items.extend(fields.iter().map(|(first, _)| first.clone()));
+                }
+                if let Some(lambda) = lambda_map.get(&object_id) {
+                    items.extend(lambda.captured_items.iter().cloned());
+                }
+            }
+            Item::Arrayref(array_id) if array_ids.insert(array_id) => {
+                match array_map.get(&array_id) {
+                    Some(Array::Array(elements)) | Some(Array::Custom(elements)) => {
+                        items.extend(elements.borrow().iter().cloned())
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    (object_ids, array_ids)
+}
+
+#[test]
+fn test_collect() {
+    use crate::array::{get_array_size, ARRAY_ELEMENT_SIZE, CUSTOM_ELEMENT_SIZE};
+    use crate::object::Objectref;
+    use std::cell::RefCell;
+
+    let mut object_map = ObjectMap::new();
+    let mut array_map = ArrayMap::new();
+    let mut lambda_map = HashMap::new();
+    let leaf = object_map.add(Objectref::new(0, RefCell::new(vec![])));
+    let root = object_map.add(Objectref::new(
+        0,
+        RefCell::new(vec![(Item::Objectref(leaf), Item::Null)]),
+    ));
+    let garbage = object_map.add(Objectref::new(0, RefCell::new(vec![])));
+    let array = array_map.add(
+        Array::Custom(RefCell::new(vec![Item::Objectref(root), Item::Null])),
+        1,
+        get_array_size(2, CUSTOM_ELEMENT_SIZE),
+    );
+    let garbage_array = array_map.add(
+        Array::Array(RefCell::new(vec![Item::Arrayref(array)])),
+        2,
+        get_array_size(1, ARRAY_ELEMENT_SIZE),
+    );
+
+    let mut gc = GarbageCollector::new(usize::MAX);
+    gc.collect(
+        vec![Item::Arrayref(array), Item::Int(3)],
+        &mut object_map,
+        &mut array_map,
+        &mut lambda_map,
+    );
+    assert!(object_map.get(&root).is_some());
+    assert!(object_map.get(&leaf).is_some());
+    assert!(object_map.get(&garbage).is_none());
+    assert!(array_map.get(&array).is_some());
+    assert!(array_map.get(&garbage_array).is_none());
+    assert_eq!(array_map.get_class_name(&garbage_array), None);
+    assert_eq!(
+        get_heap_size(&object_map, &array_map),
+        object_map.get(&root).unwrap().size()
+            + object_map.get(&leaf).unwrap().size()
+            + array_map.get_size(&array).unwrap()
+    );
+}
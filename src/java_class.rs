@@ -1,11 +1,24 @@
 pub mod builtin;
 pub mod custom;
 pub mod default;
+pub mod layout;
+pub mod resolution;
 
-#[derive(Debug)]
+use std::rc::Rc;
+
+// the classes are shared by the frames which are running. they are immutable after loading
+#[derive(Clone, Debug)]
 pub enum JavaClass {
-    BuiltIn(builtin::BuiltIn),
-    Custom(custom::Custom),
+    BuiltIn(Rc<builtin::BuiltIn>),
+    Custom(Rc<custom::Custom>),
+}
+
+// JVMS 5.5. a class which isn't in the map is not initialized yet
+#[derive(Debug, PartialEq)]
+pub enum ClassInitState {
+    BeingInitialized,
+    Initialized,
+    Erroneous,
 }
 
 impl JavaClass {
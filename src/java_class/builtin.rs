@@ -1,26 +1,45 @@
 use std::collections::HashMap;
 
 use crate::constant::ConstantPool;
+use crate::object::ObjectMap;
 use crate::operand::Item;
 use crate::stackframe::Stackframe;
 use crate::string_pool::StringPool;
+use crate::utils::format_floating_point;
 use crate::wasm::print_log;
 
 #[derive(Debug)]
 pub struct BuiltIn {
     pub class_name: usize,
+    pub super_class_name: Option<usize>,
+    pub interfaces: Vec<usize>,
     pub methods: HashMap<usize, BuiltInMethod>,
+    // the names of the instance fields. they are initialized with Null
+    pub fields: Vec<usize>,
+    // (field_name, value)
+    pub static_fields: Vec<(usize, (Item, Item))>,
 }
 
 impl BuiltIn {
-    pub fn new(class_name: usize) -> BuiltIn {
+    pub fn new(class_name: usize, super_class_name: Option<usize>) -> BuiltIn {
         BuiltIn {
             class_name,
+            super_class_name,
+            interfaces: vec![],
             methods: HashMap::new(),
+            fields: vec![],
+            static_fields: vec![],
         }
     }
 }
 
+// the slots of the fields which are declared in java/lang/Throwable and java/lang/Enum.
+// they are the first fields because java/lang/Object doesn't have fields
+pub const THROWABLE_DETAIL_MESSAGE_SLOT: usize = 0;
+pub const THROWABLE_CAUSE_SLOT: usize = 1;
+pub const ENUM_NAME_SLOT: usize = 0;
+pub const ENUM_ORDINAL_SLOT: usize = 1;
+
 #[derive(Debug)]
 pub enum BuiltInLocal {
     Println,
@@ -37,29 +56,35 @@ impl BuiltInMethod {
         BuiltInMethod { name, code_type }
     }
 
-    pub fn parameter_length(&self, string_map: &mut StringPool, descriptor: usize) -> usize {
-        let descriptor = string_map.get_value(&descriptor);
+    // the receiver is passed as the first local variable of the instance methods
+    pub fn is_static(&self) -> bool {
         match self.code_type {
-            BuitlInCodeType::Println => match descriptor.as_ref() {
-                "(J)V" | "(D)V" => 2,
-                _ => 1,
-            },
-            BuitlInCodeType::JavaLangSystemInit
-            | BuitlInCodeType::JavaLangObjectInit
-            | BuitlInCodeType::JavaLangObjectToString => 1,
+            // Integer.toString(int)
+            BuitlInCodeType::JavaLangObjectToString
+            | BuitlInCodeType::JavaLangStringValueOf
+            | BuitlInCodeType::JavaLangMathAbs
+            | BuitlInCodeType::JavaLangMathMax
+            | BuitlInCodeType::JavaLangMathMin
+            | BuitlInCodeType::JavaLangEnumValueOf => true,
+            _ => false,
         }
     }
 
+    // the returned items are pushed to the operand stack of the caller. long and double use two slots
     pub fn execute(
-        &mut self,
+        &self,
         string_map: &mut StringPool,
         constant_pool: &ConstantPool,
+        object_map: &mut ObjectMap,
         stackframes: &mut Vec<Stackframe>,
-    ) {
-        let mut stackframe = stackframes.pop().expect("should has stack_frame");
+    ) -> Vec<Item> {
+        let stackframe = stackframes.pop().expect("should has stack_frame");
         match self.code_type {
             BuitlInCodeType::Println => {
-                if let Some(item) = stackframe.local_variables.get(0) {
+                // println() doesn't have the argument
+                if stackframe.local_variables.len() == 1 {
+                    print_log("");
+                } else if let Some(item) = stackframe.local_variables.get(1) {
                     match item {
                         Item::Fieldref(index) => {
                             let value =
@@ -73,72 +98,320 @@ impl BuiltInMethod {
                         Item::Int(value) => {
                             print_log(&format!("{}", value));
                         }
-                        Item::Long(second) => {
-                            if let Some(Item::Long(first)) = stackframe.local_variables.get(1) {
-                                let value: u64 = (((*first as u64) << 32) as u64) | *second as u64;
-                                let value = if value > 0x7fffffffffffffff {
-                                    -1 * ((value ^ 0xffffffffffffffff) + 1) as i64
-                                } else {
-                                    value as i64
-                                };
-                                print_log(&format!("{}", value));
-                            } else {
-                                unreachable!("should exist long second item")
-                            }
-                            let _ = stackframe.operand_stack.stack.pop();
+                        Item::Long(value) => {
+                            print_log(&format!("{}", value));
                         }
                         // TBD should fix to output value correctly
                         Item::Objectref(object_ref) => {
                             print_log(&format!("objectref: {}", object_ref));
                         }
                         Item::Float(value) => {
-                            print_log(&format!("{}", value));
+                            print_log(&format_floating_point(*value));
                         }
-                        Item::Double(second) => {
-                            if let Some(Item::Double(first)) = stackframe.local_variables.get(1) {
-                                let value: u64 = (((*first as u64) << 32) as u64) | *second as u64;
-                                let s: i64 = if value >> 63 == 0 as u64 { 1 } else { -1 };
-                                let e = (value >> 52 as i32) & 0x7ff;
-                                let m = if e == 0 {
-                                    ((value & 0xfffffffffffff) << 1) as i64
-                                } else {
-                                    ((value & 0xfffffffffffff) | 0x10000000000000) as i64
-                                };
-                                print_log(&format!(
-                                    "{}",
-                                    (s * m) as f64 * f64::powf(2.0f64, e as f64 - 1075 as f64)
-                                ));
-                            } else {
-                                unreachable!("should exist long second item")
-                            }
-                            let _ = stackframe.operand_stack.stack.pop();
+                        Item::Null => {
+                            print_log("null");
+                        }
+                        Item::Double(value) => {
+                            print_log(&format_floating_point(*value));
                         }
                         _ => unimplemented!(),
                     };
-                    let _ = stackframe.operand_stack.stack.pop();
                 } else {
                     unreachable!("should have a argument for println")
                 }
+                vec![]
+            }
+            BuitlInCodeType::JavaLangSystemInit | BuitlInCodeType::JavaLangObjectInit => vec![],
+            // the identities are compared
+            BuitlInCodeType::JavaLangObjectEquals => {
+                let variables = &stackframe.local_variables;
+                let is_equal = match (variables.get(0), variables.get(1)) {
+                    (Some(Item::Objectref(left)), Some(Item::Objectref(right))) => left == right,
+                    _ => false,
+                };
+                vec![Item::Int(is_equal as i32)]
+            }
+            // the id of ObjectMap is used as the identity hash code
+            BuitlInCodeType::JavaLangObjectHashCode => {
+                let object_id = get_receiver_id(&stackframe);
+                vec![Item::Int(object_id as i32)]
             }
-            BuitlInCodeType::JavaLangSystemInit | BuitlInCodeType::JavaLangObjectInit => {}
             BuitlInCodeType::JavaLangObjectToString => {
                 let val = if let Some(Item::Int(val)) = stackframe.local_variables.get(0) {
                     val
                 } else {
                     unreachable!("should have a argument for toString")
                 };
-                let stackframe = stackframes.last_mut().expect("should exist stackframe");
                 let string_id = string_map.insert(val.to_string());
-                stackframe.operand_stack.stack.push(Item::String(string_id));
+                vec![Item::String(string_id)]
+            }
+            // (), (String), (Throwable) and (String, Throwable)
+            BuitlInCodeType::JavaLangThrowableInit => {
+                let object_id = get_receiver_id(&stackframe);
+                let (message, cause) = match &stackframe.local_variables[1..] {
+                    [] => (Item::Null, Item::Null),
+                    // the message is cause.toString() like Throwable(Throwable cause)
+                    [Item::Objectref(cause_id)] => {
+                        let description = describe_throwable(string_map, object_map, *cause_id);
+                        let message = Item::String(string_map.insert(description));
+                        (message, Item::Objectref(*cause_id))
+                    }
+                    [message] => (message.clone(), Item::Null),
+                    [message, cause] => (message.clone(), cause.clone()),
+                    items => unreachable!("should be arguments of Throwable. actual {:?}", items),
+                };
+                let object_ref = object_map
+                    .get(&object_id)
+                    .expect("should exist object_ref in object_map");
+                let mut fields = object_ref.fields.borrow_mut();
+                fields[THROWABLE_DETAIL_MESSAGE_SLOT] = (message, Item::Null);
+                fields[THROWABLE_CAUSE_SLOT] = (cause, Item::Null);
+                vec![]
+            }
+            BuitlInCodeType::JavaLangThrowableGetMessage => {
+                let object_id = get_receiver_id(&stackframe);
+                vec![get_detail_message(object_map, object_id)]
+            }
+            BuitlInCodeType::JavaLangThrowableGetCause => {
+                let object_id = get_receiver_id(&stackframe);
+                vec![get_cause(object_map, object_id)]
+            }
+            // toString of the object may be invoked. so it is executed by Context
+            BuitlInCodeType::JavaLangStringValueOf => {
+                unreachable!("String.valueOf should be executed by Context")
+            }
+            BuitlInCodeType::JavaLangThrowableToString => {
+                let object_id = get_receiver_id(&stackframe);
+                let description = describe_throwable(string_map, object_map, object_id);
+                let string_id = string_map.insert(description);
+                vec![Item::String(string_id)]
+            }
+            BuitlInCodeType::JavaLangStringHashCode => {
+                let value = match stackframe.local_variables.get(0) {
+                    Some(Item::String(id)) => string_map.get_value(id),
+                    item => unreachable!("should be String for hashCode. actual {:?}", item),
+                };
+                vec![Item::Int(get_string_hash_code(&value))]
+            }
+            BuitlInCodeType::JavaLangStringEquals => {
+                let variables = &stackframe.local_variables;
+                let is_equal = match (variables.get(0), variables.get(1)) {
+                    (Some(Item::String(left)), Some(Item::String(right))) => {
+                        left == right || string_map.get_value(left) == string_map.get_value(right)
+                    }
+                    _ => false,
+                };
+                vec![Item::Int(is_equal as i32)]
+            }
+            BuitlInCodeType::JavaLangEnumInit => {
+                let object_id = get_receiver_id(&stackframe);
+                let name = stackframe
+                    .local_variables
+                    .get(1)
+                    .cloned()
+                    .expect("should have name for Enum.<init>");
+                let ordinal = stackframe
+                    .local_variables
+                    .get(2)
+                    .cloned()
+                    .expect("should have ordinal for Enum.<init>");
+                let object_ref = object_map
+                    .get(&object_id)
+                    .expect("should exist object_ref in object_map");
+                let mut fields = object_ref.fields.borrow_mut();
+                fields[ENUM_NAME_SLOT] = (name, Item::Null);
+                fields[ENUM_ORDINAL_SLOT] = (ordinal, Item::Null);
+                vec![]
+            }
+            BuitlInCodeType::JavaLangEnumOrdinal => {
+                let object_id = get_receiver_id(&stackframe);
+                vec![get_enum_field(object_map, object_id, ENUM_ORDINAL_SLOT)]
+            }
+            // toString also returns the name
+            BuitlInCodeType::JavaLangEnumName => {
+                let object_id = get_receiver_id(&stackframe);
+                vec![get_enum_field(object_map, object_id, ENUM_NAME_SLOT)]
+            }
+            BuitlInCodeType::JavaLangEnumCompareTo => {
+                let object_id = get_receiver_id(&stackframe);
+                let other_id = match stackframe.local_variables.get(1) {
+                    Some(Item::Objectref(other_id)) => *other_id,
+                    item => unreachable!("should be enum for compareTo. actual {:?}", item),
+                };
+                match (
+                    get_enum_field(object_map, object_id, ENUM_ORDINAL_SLOT),
+                    get_enum_field(object_map, other_id, ENUM_ORDINAL_SLOT),
+                ) {
+                    (Item::Int(ordinal), Item::Int(other_ordinal)) => {
+                        vec![Item::Int(ordinal - other_ordinal)]
+                    }
+                    items => unreachable!("should be ordinals. actual {:?}", items),
+                }
+            }
+            // the enum constants are stored in the static fields. so it is executed by Context
+            BuitlInCodeType::JavaLangEnumValueOf => {
+                unreachable!("Enum.valueOf should be executed by Context")
+            }
+            BuitlInCodeType::JavaLangMathAbs => match stackframe.local_variables.get(0) {
+                Some(Item::Int(value)) => vec![Item::Int(value.wrapping_abs())],
+                Some(Item::Long(value)) => {
+                    vec![
+                        Item::Long(value.wrapping_abs()),
+                        Item::Long(value.wrapping_abs()),
+                    ]
+                }
+                Some(Item::Float(value)) => vec![Item::Float(value.abs())],
+                Some(Item::Double(value)) => {
+                    vec![Item::Double(value.abs()), Item::Double(value.abs())]
+                }
+                item => unreachable!("should be a number for Math.abs. actual {:?}", item),
+            },
+            BuitlInCodeType::JavaLangMathMax | BuitlInCodeType::JavaLangMathMin => {
+                let is_max = match self.code_type {
+                    BuitlInCodeType::JavaLangMathMax => true,
+                    _ => false,
+                };
+                let variables = &stackframe.local_variables;
+                match (variables.get(0), variables.get(variables.len() / 2)) {
+                    (Some(Item::Int(left)), Some(Item::Int(right))) => {
+                        let value = if is_max {
+                            left.max(right)
+                        } else {
+                            left.min(right)
+                        };
+                        vec![Item::Int(*value)]
+                    }
+                    (Some(Item::Long(left)), Some(Item::Long(right))) => {
+                        let value = if is_max {
+                            left.max(right)
+                        } else {
+                            left.min(right)
+                        };
+                        vec![Item::Long(*value), Item::Long(*value)]
+                    }
+                    (Some(Item::Float(left)), Some(Item::Float(right))) => {
+                        let value = compare_floating_point(*left as f64, *right as f64, is_max);
+                        vec![Item::Float(value as f32)]
+                    }
+                    (Some(Item::Double(left)), Some(Item::Double(right))) => {
+                        let value = compare_floating_point(*left, *right, is_max);
+                        vec![Item::Double(value), Item::Double(value)]
+                    }
+                    items => {
+                        unreachable!("should be numbers for Math.max or min. actual {:?}", items)
+                    }
+                }
             }
         }
     }
 }
 
-#[derive(Debug)]
+// same as Math.max and Math.min. NaN wins and 0.0 is greater than -0.0
+fn compare_floating_point(left: f64, right: f64, is_max: bool) -> f64 {
+    if left.is_nan() || right.is_nan() {
+        f64::NAN
+    } else if left == right {
+        match (left.is_sign_negative(), is_max) {
+            (true, true) | (false, false) => right,
+            _ => left,
+        }
+    } else if (left > right) == is_max {
+        left
+    } else {
+        right
+    }
+}
+
+fn get_receiver_id(stackframe: &Stackframe) -> usize {
+    if let Some(Item::Objectref(object_id)) = stackframe.local_variables.get(0) {
+        *object_id
+    } else {
+        unreachable!("should have an object as receiver")
+    }
+}
+
+// same as String.hashCode. s[0]*31^(n-1) + s[1]*31^(n-2) + ... + s[n-1] for UTF-16 code units
+pub fn get_string_hash_code(value: &str) -> i32 {
+    value.encode_utf16().fold(0i32, |hash, code_unit| {
+        hash.wrapping_mul(31).wrapping_add(code_unit as i32)
+    })
+}
+
+pub fn get_enum_field(object_map: &ObjectMap, object_id: usize, slot: usize) -> Item {
+    let object_ref = object_map
+        .get(&object_id)
+        .expect("should exist object_ref in object_map");
+    let fields = object_ref.fields.borrow();
+    fields[slot].0.clone()
+}
+
+pub fn get_cause(object_map: &ObjectMap, object_id: usize) -> Item {
+    let object_ref = object_map
+        .get(&object_id)
+        .expect("should exist object_ref in object_map");
+    let fields = object_ref.fields.borrow();
+    fields[THROWABLE_CAUSE_SLOT].0.clone()
+}
+
+fn get_detail_message(object_map: &ObjectMap, object_id: usize) -> Item {
+    let object_ref = object_map
+        .get(&object_id)
+        .expect("should exist object_ref in object_map");
+    let fields = object_ref.fields.borrow();
+    fields[THROWABLE_DETAIL_MESSAGE_SLOT].0.clone()
+}
+
+// same format as Throwable.toString. e.g. java.lang.RuntimeException: message
+pub fn describe_throwable(
+    string_map: &mut StringPool,
+    object_map: &ObjectMap,
+    object_id: usize,
+) -> String {
+    let class_name_id = object_map
+        .get(&object_id)
+        .expect("should exist object_ref in object_map")
+        .class_name_id;
+    let class_name = string_map.get_value(&class_name_id).replace("/", ".");
+    match get_detail_message(object_map, object_id) {
+        Item::String(message_id) => {
+            format!("{}: {}", class_name, string_map.get_value(&message_id))
+        }
+        _ => class_name,
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub enum BuitlInCodeType {
     Println,
     JavaLangObjectInit,
+    JavaLangObjectEquals,
+    JavaLangObjectHashCode,
     JavaLangSystemInit,
     JavaLangObjectToString,
+    JavaLangThrowableInit,
+    JavaLangThrowableGetMessage,
+    JavaLangThrowableGetCause,
+    JavaLangThrowableToString,
+    JavaLangStringValueOf,
+    JavaLangMathAbs,
+    JavaLangMathMax,
+    JavaLangMathMin,
+    JavaLangStringHashCode,
+    JavaLangStringEquals,
+    JavaLangEnumInit,
+    JavaLangEnumOrdinal,
+    JavaLangEnumName,
+    JavaLangEnumCompareTo,
+    JavaLangEnumValueOf,
+}
+
+#[test]
+fn test_get_string_hash_code() {
+    assert_eq!(get_string_hash_code(""), 0);
+    assert_eq!(get_string_hash_code("start"), 109757538);
+    assert_eq!(get_string_hash_code("Aa"), get_string_hash_code("BB"));
+    assert_eq!(
+        get_string_hash_code("a much longer command name"),
+        980519715
+    );
 }
@@ -1,10 +1,12 @@
 use crate::attribute::code::Code;
-use crate::attribute::defs::Attribute;
+use crate::attribute::defs::{Attribute, BootstrapMethod};
 use crate::constant::ConstantPool;
 use crate::field::{Field, FieldDescriptor};
+use crate::java_class::resolution::ResolutionCache;
 use crate::method::{Method, MethodAccessFlag};
 use crate::string_pool::StringPool;
 use crate::utils::*;
+use std::collections::HashMap;
 use std::fmt;
 
 #[derive(Debug)]
@@ -28,6 +30,9 @@ pub struct Custom {
     pub methods: Vec<Method>,       // method_info    methods[methods_count];
     pub attributes_count: usize,    // u2
     pub attributes: Vec<Attribute>, // attribute_info attributes[attributes_count];
+    // (method_name, method_descriptor) => index of methods
+    pub method_indexes: HashMap<(usize, usize), usize>,
+    pub resolution_cache: ResolutionCache,
 }
 
 impl Custom {
@@ -60,7 +65,7 @@ impl Custom {
         let (fields_count, mut index) = extract_x_byte_as_usize(input, index, 2);
         let mut fields = Vec::with_capacity(fields_count);
         for _ in 0..fields_count {
-            let (field, updated_index) = Field::new(input, index);
+            let (field, updated_index) = Field::new(string_pool, &cp_info, input, index);
             index = updated_index;
             fields.push(field);
         }
@@ -81,6 +86,17 @@ impl Custom {
             index = updated_index;
             attributes.push(attribute);
         }
+
+        let method_indexes = methods
+            .iter()
+            .enumerate()
+            .map(|(method_index, method)| {
+                let name = cp_info.get_utf8(method.name_index);
+                let descriptor = cp_info.get_utf8(method.descriptor_index);
+                ((name, descriptor), method_index)
+            })
+            .collect();
+        let resolution_cache = ResolutionCache::new(constant_pool_count);
         (
             Custom {
                 magic,
@@ -99,14 +115,21 @@ impl Custom {
                 methods,
                 attributes_count,
                 attributes,
+                method_indexes,
+                resolution_cache,
             },
             index,
         )
     }
 
     pub fn get_entry_method(&self) -> Option<&Method> {
+        self.get_entry_method_index()
+            .map(|method_index| &self.methods[method_index])
+    }
+
+    pub fn get_entry_method_index(&self) -> Option<usize> {
         if let Some(main_index) = self.cp_info.get_main_index() {
-            return self.methods.iter().find(|method| {
+            return self.methods.iter().position(|method| {
                 method
                     .access_flags
                     .0
@@ -123,35 +146,22 @@ impl Custom {
         );
     }
 
+    pub fn get_clinit_method_index(&self) -> Option<usize> {
+        let clinit_index = self.cp_info.get_clinit_index()?;
+        self.methods.iter().position(|method| {
+            method
+                .access_flags
+                .0
+                .iter()
+                .find(|flag| **flag == MethodAccessFlag::AccStatic)
+                .is_some()
+                && method.name_index == clinit_index
+        })
+    }
+
     pub fn get_clinit_code(&self) -> Option<&Code> {
-        if let Some(clinit_index) = self.cp_info.get_clinit_index() {
-            let method_option = self.methods.iter().find(|method| {
-                method
-                    .access_flags
-                    .0
-                    .iter()
-                    .find(|flag| **flag == MethodAccessFlag::AccStatic)
-                    .is_some()
-                    && method.name_index == clinit_index
-            });
-            if let Some(method) = method_option {
-                if let Some(Attribute::Code(code)) = method.attribute_info.iter().find(|attr| {
-                    if let Attribute::Code(_) = attr {
-                        true
-                    } else {
-                        false
-                    }
-                }) {
-                    Some(code)
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.get_clinit_method_index()
+            .and_then(|method_index| self.methods[method_index].extract_code())
     }
 
     pub fn this_class_name(&self) -> usize {
@@ -159,6 +169,62 @@ impl Custom {
         self.cp_info.get_utf8(class_ref.name_index)
     }
 
+    // java/lang/Object is the only class which doesn't have super class
+    pub fn super_class_name(&self) -> Option<usize> {
+        if self.super_class == 0 {
+            None
+        } else {
+            Some(self.cp_info.get_class_ref_name(self.super_class))
+        }
+    }
+
+    pub fn get_interface_names(&self) -> Vec<usize> {
+        self.interfaces
+            .iter()
+            .map(|Interface(index)| self.cp_info.get_class_ref_name(*index))
+            .collect()
+    }
+
+    // (outer class name, simple name) of the member class. e.g. Outer$Inner => (Outer, Inner)
+    pub fn get_outer_class(&self) -> Option<(usize, usize)> {
+        let this_class_name = self.this_class_name();
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::InnerClasses(inner_classes) => inner_classes
+                    .classes
+                    .iter()
+                    .find(|class| {
+                        class.outer_class_info_index != 0
+                            && self
+                                .cp_info
+                                .get_class_ref_name(class.inner_class_info_index as usize)
+                                == this_class_name
+                    })
+                    .map(|class| {
+                        (
+                            self.cp_info
+                                .get_class_ref_name(class.outer_class_info_index as usize),
+                            self.cp_info.get_utf8(class.inner_name_index as usize),
+                        )
+                    }),
+                _ => None,
+            })
+    }
+
+    pub fn get_bootstrap_method(&self, index: usize) -> &BootstrapMethod {
+        self.attributes
+            .iter()
+            .find_map(|attribute| {
+                if let Attribute::BootstrapMethods(bootstrap_methods) = attribute {
+                    bootstrap_methods.bootstrap_methods.get(index)
+                } else {
+                    None
+                }
+            })
+            .expect("should exist bootstrap method in BootstrapMethods")
+    }
+
     pub fn get_method(&self, name_index: usize, descriptor_index: usize) -> Option<&Method> {
         self.methods
             .iter()
@@ -166,10 +232,12 @@ impl Custom {
     }
 
     pub fn get_method_by_string(&self, name: usize, descriptor: usize) -> Option<&Method> {
-        self.methods.iter().find(|item| {
-            (self.cp_info.get_utf8(item.name_index) == name)
-                && (self.cp_info.get_utf8(item.descriptor_index) == descriptor)
-        })
+        self.get_method_index_by_string(name, descriptor)
+            .map(|method_index| &self.methods[method_index])
+    }
+
+    pub fn get_method_index_by_string(&self, name: usize, descriptor: usize) -> Option<usize> {
+        self.method_indexes.get(&(name, descriptor)).cloned()
     }
 
     pub fn get_method_code(&self, name_index: usize, descriptor_index: usize) -> Option<&Code> {
@@ -2,8 +2,10 @@ use crate::java_class::{
     builtin::{BuiltIn, BuiltInMethod, BuitlInCodeType},
     JavaClass,
 };
+use crate::operand::Item;
 use crate::string_pool::StringPool;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 pub fn setup_class_map(string_pool: &mut StringPool) -> HashMap<usize, JavaClass> {
     let mut class_map = HashMap::new();
@@ -11,50 +13,250 @@ pub fn setup_class_map(string_pool: &mut StringPool) -> HashMap<usize, JavaClass
     let (java_lang_object_name, java_lang_object) = create_java_lang_object(string_pool);
     let (java_lang_integer_name, java_lang_integer) = create_java_lang_integer(string_pool);
     let (java_lang_system_name, java_lang_system) = create_java_lang_system(string_pool);
+    let (java_lang_string_name, java_lang_string) = create_java_lang_string(string_pool);
+    let (java_lang_math_name, java_lang_math) = create_java_lang_math(string_pool);
+    let (java_lang_enum_name, java_lang_enum) = create_java_lang_enum(string_pool);
 
     class_map.insert(print_stream_name, print_stream);
     class_map.insert(java_lang_object_name, java_lang_object);
     class_map.insert(java_lang_integer_name, java_lang_integer);
     class_map.insert(java_lang_system_name, java_lang_system);
+    class_map.insert(java_lang_string_name, java_lang_string);
+    class_map.insert(java_lang_math_name, java_lang_math);
+    class_map.insert(java_lang_enum_name, java_lang_enum);
+
+    for interface_name in INTERFACES.iter() {
+        let (interface_name, interface) = create_interface(string_pool, interface_name);
+        class_map.insert(interface_name, interface);
+    }
+
+    for (class_name, super_class_name) in THROWABLE_CLASSES.iter() {
+        let (throwable_name, throwable) =
+            create_throwable(string_pool, class_name, super_class_name);
+        class_map.insert(throwable_name, throwable);
+    }
     class_map
 }
 
+// the methods of these interfaces are always implemented by custom classes or builtin classes
+const INTERFACES: [&str; 4] = [
+    "java/lang/Comparable",
+    "java/lang/Runnable",
+    "java/lang/CharSequence",
+    "java/io/Serializable",
+];
+
+fn to_class_names(string_pool: &mut StringPool, class_names: &[&str]) -> Vec<usize> {
+    class_names
+        .iter()
+        .map(|class_name| string_pool.insert(String::from(*class_name)))
+        .collect()
+}
+
+fn create_interface(string_pool: &mut StringPool, interface_name: &str) -> (usize, JavaClass) {
+    let interface_name_id = string_pool.insert(String::from(interface_name));
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let interface = BuiltIn::new(interface_name_id, Some(java_lang_object_name_id));
+    (interface_name_id, JavaClass::BuiltIn(Rc::new(interface)))
+}
+
+// (class_name, super_class_name)
+const THROWABLE_CLASSES: [(&str, &str); 22] = [
+    ("java/lang/Throwable", "java/lang/Object"),
+    ("java/lang/Exception", "java/lang/Throwable"),
+    ("java/lang/Error", "java/lang/Throwable"),
+    ("java/lang/RuntimeException", "java/lang/Exception"),
+    ("java/lang/LinkageError", "java/lang/Error"),
+    (
+        "java/lang/ExceptionInInitializerError",
+        "java/lang/LinkageError",
+    ),
+    ("java/lang/NoClassDefFoundError", "java/lang/LinkageError"),
+    ("java/lang/VirtualMachineError", "java/lang/Error"),
+    (
+        "java/lang/StackOverflowError",
+        "java/lang/VirtualMachineError",
+    ),
+    (
+        "java/lang/OutOfMemoryError",
+        "java/lang/VirtualMachineError",
+    ),
+    (
+        "java/lang/IncompatibleClassChangeError",
+        "java/lang/LinkageError",
+    ),
+    (
+        "java/lang/NoSuchFieldError",
+        "java/lang/IncompatibleClassChangeError",
+    ),
+    (
+        "java/lang/IllegalArgumentException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/IllegalStateException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/UnsupportedOperationException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/IndexOutOfBoundsException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/ArithmeticException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/ArrayIndexOutOfBoundsException",
+        "java/lang/IndexOutOfBoundsException",
+    ),
+    (
+        "java/lang/NegativeArraySizeException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/NullPointerException",
+        "java/lang/RuntimeException",
+    ),
+    ("java/lang/ClassCastException", "java/lang/RuntimeException"),
+    (
+        "java/lang/ArrayStoreException",
+        "java/lang/RuntimeException",
+    ),
+];
+
+fn create_throwable(
+    string_pool: &mut StringPool,
+    class_name: &str,
+    super_class_name: &str,
+) -> (usize, JavaClass) {
+    let class_name_id = string_pool.insert(String::from(class_name));
+    let super_class_name_id = string_pool.insert(String::from(super_class_name));
+    let mut throwable = BuiltIn::new(class_name_id, Some(super_class_name_id));
+    // the order is the same as THROWABLE_DETAIL_MESSAGE_SLOT and THROWABLE_CAUSE_SLOT
+    if class_name == "java/lang/Throwable" {
+        throwable.interfaces = to_class_names(string_pool, &["java/io/Serializable"]);
+        throwable.fields = vec![
+            string_pool.insert(String::from("detailMessage")),
+            string_pool.insert(String::from("cause")),
+        ];
+    }
+
+    let init_name_id = string_pool.insert(String::from("<init>"));
+    let init = BuiltInMethod::new(init_name_id, BuitlInCodeType::JavaLangThrowableInit);
+    throwable.methods.insert(init_name_id, init);
+
+    let get_message_name_id = string_pool.insert(String::from("getMessage"));
+    let get_message = BuiltInMethod::new(
+        get_message_name_id,
+        BuitlInCodeType::JavaLangThrowableGetMessage,
+    );
+    throwable.methods.insert(get_message_name_id, get_message);
+
+    let get_cause_name_id = string_pool.insert(String::from("getCause"));
+    let get_cause = BuiltInMethod::new(
+        get_cause_name_id,
+        BuitlInCodeType::JavaLangThrowableGetCause,
+    );
+    throwable.methods.insert(get_cause_name_id, get_cause);
+
+    let to_string_name_id = string_pool.insert(String::from("toString"));
+    let to_string = BuiltInMethod::new(
+        to_string_name_id,
+        BuitlInCodeType::JavaLangThrowableToString,
+    );
+    throwable.methods.insert(to_string_name_id, to_string);
+
+    (class_name_id, JavaClass::BuiltIn(Rc::new(throwable)))
+}
+
 fn create_print_stream(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let class_name_id = string_pool.insert(String::from("java/io/PrintStream"));
-    let mut print_stream = BuiltIn::new(class_name_id);
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut print_stream = BuiltIn::new(class_name_id, Some(java_lang_object_name_id));
     let println_name_id = string_pool.insert(String::from("println"));
     let println = BuiltInMethod::new(println_name_id, BuitlInCodeType::Println);
     print_stream.methods.insert(println_name_id, println);
-    (class_name_id, JavaClass::BuiltIn(print_stream))
+    (class_name_id, JavaClass::BuiltIn(Rc::new(print_stream)))
 }
 
 fn create_java_lang_object(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
-    let mut java_lang_object = BuiltIn::new(java_lang_object_name_id);
-    let init_name_id = string_pool.insert(String::from("<init>"));
-    let init = BuiltInMethod::new(init_name_id, BuitlInCodeType::JavaLangObjectInit);
-    java_lang_object.methods.insert(init_name_id, init);
+    let mut java_lang_object = BuiltIn::new(java_lang_object_name_id, None);
+    // equals and hashCode are identity based and inherited by every class
+    for (method_name, code_type) in vec![
+        ("<init>", BuitlInCodeType::JavaLangObjectInit),
+        ("equals", BuitlInCodeType::JavaLangObjectEquals),
+        ("hashCode", BuitlInCodeType::JavaLangObjectHashCode),
+    ] {
+        let method_name_id = string_pool.insert(String::from(method_name));
+        let method = BuiltInMethod::new(method_name_id, code_type);
+        java_lang_object.methods.insert(method_name_id, method);
+    }
     (
         java_lang_object_name_id,
-        JavaClass::BuiltIn(java_lang_object),
+        JavaClass::BuiltIn(Rc::new(java_lang_object)),
     )
 }
 
 fn create_java_lang_system(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let java_lang_system_name_id = string_pool.insert(String::from("java/lang/System"));
-    let mut java_lang_system = BuiltIn::new(java_lang_system_name_id);
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut java_lang_system =
+        BuiltIn::new(java_lang_system_name_id, Some(java_lang_object_name_id));
     let init_name_id = string_pool.insert(String::from("<init>"));
     let init = BuiltInMethod::new(init_name_id, BuitlInCodeType::JavaLangSystemInit);
     java_lang_system.methods.insert(init_name_id, init);
+    let out_name_id = string_pool.insert(String::from("out"));
+    let print_stream_name_id = string_pool.insert(String::from("java/io/PrintStream"));
+    java_lang_system.static_fields.push((
+        out_name_id,
+        (Item::Classref(print_stream_name_id), Item::Null),
+    ));
     (
         java_lang_system_name_id,
-        JavaClass::BuiltIn(java_lang_system),
+        JavaClass::BuiltIn(Rc::new(java_lang_system)),
+    )
+}
+
+fn create_java_lang_string(string_pool: &mut StringPool) -> (usize, JavaClass) {
+    let java_lang_string_name_id = string_pool.insert(String::from("java/lang/String"));
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut java_lang_string =
+        BuiltIn::new(java_lang_string_name_id, Some(java_lang_object_name_id));
+    java_lang_string.interfaces = to_class_names(
+        string_pool,
+        &[
+            "java/lang/Comparable",
+            "java/lang/CharSequence",
+            "java/io/Serializable",
+        ],
+    );
+    let value_of_name_id = string_pool.insert(String::from("valueOf"));
+    let value_of = BuiltInMethod::new(value_of_name_id, BuitlInCodeType::JavaLangStringValueOf);
+    java_lang_string.methods.insert(value_of_name_id, value_of);
+    let hash_code_name_id = string_pool.insert(String::from("hashCode"));
+    let hash_code = BuiltInMethod::new(hash_code_name_id, BuitlInCodeType::JavaLangStringHashCode);
+    java_lang_string
+        .methods
+        .insert(hash_code_name_id, hash_code);
+    let equals_name_id = string_pool.insert(String::from("equals"));
+    let equals = BuiltInMethod::new(equals_name_id, BuitlInCodeType::JavaLangStringEquals);
+    java_lang_string.methods.insert(equals_name_id, equals);
+    (
+        java_lang_string_name_id,
+        JavaClass::BuiltIn(Rc::new(java_lang_string)),
     )
 }
 
 fn create_java_lang_integer(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let java_lang_integer_name_id = string_pool.insert(String::from("java/lang/Integer"));
-    let mut java_lang_integer = BuiltIn::new(java_lang_integer_name_id);
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut java_lang_integer =
+        BuiltIn::new(java_lang_integer_name_id, Some(java_lang_object_name_id));
     let to_string_name_id = string_pool.insert(String::from("toString"));
     let to_string = BuiltInMethod::new(to_string_name_id, BuitlInCodeType::JavaLangObjectToString);
     java_lang_integer
@@ -62,6 +264,58 @@ fn create_java_lang_integer(string_pool: &mut StringPool) -> (usize, JavaClass)
         .insert(to_string_name_id, to_string);
     (
         java_lang_integer_name_id,
-        JavaClass::BuiltIn(java_lang_integer),
+        JavaClass::BuiltIn(Rc::new(java_lang_integer)),
+    )
+}
+
+// the overloads are distinguished by the items of the arguments
+fn create_java_lang_math(string_pool: &mut StringPool) -> (usize, JavaClass) {
+    let java_lang_math_name_id = string_pool.insert(String::from("java/lang/Math"));
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut java_lang_math = BuiltIn::new(java_lang_math_name_id, Some(java_lang_object_name_id));
+    for (method_name, code_type) in vec![
+        ("abs", BuitlInCodeType::JavaLangMathAbs),
+        ("max", BuitlInCodeType::JavaLangMathMax),
+        ("min", BuitlInCodeType::JavaLangMathMin),
+    ] {
+        let method_name_id = string_pool.insert(String::from(method_name));
+        let method = BuiltInMethod::new(method_name_id, code_type);
+        java_lang_math.methods.insert(method_name_id, method);
+    }
+    (
+        java_lang_math_name_id,
+        JavaClass::BuiltIn(Rc::new(java_lang_math)),
+    )
+}
+
+// the enum classes extend java/lang/Enum and call super(name, ordinal)
+fn create_java_lang_enum(string_pool: &mut StringPool) -> (usize, JavaClass) {
+    let java_lang_enum_name_id = string_pool.insert(String::from("java/lang/Enum"));
+    let java_lang_object_name_id = string_pool.insert(String::from("java/lang/Object"));
+    let mut java_lang_enum = BuiltIn::new(java_lang_enum_name_id, Some(java_lang_object_name_id));
+    java_lang_enum.interfaces = to_class_names(
+        string_pool,
+        &["java/lang/Comparable", "java/io/Serializable"],
+    );
+    // the order is the same as ENUM_NAME_SLOT and ENUM_ORDINAL_SLOT
+    java_lang_enum.fields = vec![
+        string_pool.insert(String::from("name")),
+        string_pool.insert(String::from("ordinal")),
+    ];
+    for (method_name, code_type) in vec![
+        ("<init>", BuitlInCodeType::JavaLangEnumInit),
+        ("ordinal", BuitlInCodeType::JavaLangEnumOrdinal),
+        ("name", BuitlInCodeType::JavaLangEnumName),
+        ("toString", BuitlInCodeType::JavaLangEnumName),
+        ("compareTo", BuitlInCodeType::JavaLangEnumCompareTo),
+        ("valueOf", BuitlInCodeType::JavaLangEnumValueOf),
+    ] {
+        let method_name_id = string_pool.insert(String::from(method_name));
+        let method = BuiltInMethod::new(method_name_id, code_type);
+        java_lang_enum.methods.insert(method_name_id, method);
+    }
+    (
+        java_lang_enum_name_id,
+        JavaClass::BuiltIn(Rc::new(java_lang_enum)),
     )
 }
@@ -0,0 +1,74 @@
+use crate::operand::Item;
+
+use std::collections::HashMap;
+
+// the fields of the super class come first. so the slot of a field is the same in every subclass
+#[derive(Clone, Debug, Default)]
+pub struct FieldLayout {
+    // field_name => slot. the field of the subclass hides the field of the super class
+    pub instance_slots: HashMap<usize, usize>,
+    // the values of the fields when the object is created
+    pub initial_values: Vec<(Item, Item)>,
+    // field_name => (class_name which declares the field, slot of static_fields in Context)
+    pub static_slots: HashMap<usize, (usize, usize)>,
+}
+
+impl FieldLayout {
+    pub fn add_instance_field(&mut self, field_name: usize, initial_value: (Item, Item)) {
+        self.instance_slots
+            .insert(field_name, self.initial_values.len());
+        self.initial_values.push(initial_value);
+    }
+
+    pub fn add_static_field(&mut self, class_name: usize, field_name: usize, slot: usize) {
+        self.static_slots.insert(field_name, (class_name, slot));
+    }
+
+    // the instance fields of the interfaces are not inherited because they don't exist
+    pub fn add_interface_static_fields(&mut self, interface_layout: &FieldLayout) {
+        self.static_slots
+            .extend(interface_layout.static_slots.iter());
+    }
+
+    pub fn get_instance_slot(&self, field_name: usize) -> Option<usize> {
+        self.instance_slots.get(&field_name).cloned()
+    }
+
+    pub fn get_static_slot(&self, field_name: usize) -> Option<(usize, usize)> {
+        self.static_slots.get(&field_name).cloned()
+    }
+}
+
+#[test]
+fn test_field_layout() {
+    let mut super_layout = FieldLayout::default();
+    super_layout.add_instance_field(1, (Item::Int(0), Item::Null));
+    super_layout.add_instance_field(2, (Item::Long(0), Item::Long(0)));
+    super_layout.add_static_field(100, 3, 0);
+
+    let mut layout = super_layout.clone();
+    layout.add_instance_field(4, (Item::Null, Item::Null));
+    // hides the field of the super class
+    layout.add_instance_field(1, (Item::Float(0.0), Item::Null));
+    layout.add_static_field(200, 3, 1);
+
+    assert_eq!(
+        layout.get_instance_slot(2),
+        super_layout.get_instance_slot(2)
+    );
+    assert_eq!(layout.get_instance_slot(4), Some(2));
+    assert_eq!(layout.get_instance_slot(1), Some(3));
+    assert_eq!(super_layout.get_instance_slot(1), Some(0));
+    assert_eq!(layout.initial_values.len(), 4);
+    assert_eq!(layout.get_static_slot(3), Some((200, 1)));
+    assert_eq!(super_layout.get_static_slot(3), Some((100, 0)));
+
+    let mut interface_layout = FieldLayout::default();
+    interface_layout.add_static_field(300, 3, 2);
+    interface_layout.add_static_field(300, 5, 3);
+    let mut implementation_layout = super_layout.clone();
+    implementation_layout.add_interface_static_fields(&interface_layout);
+    assert_eq!(implementation_layout.get_static_slot(3), Some((300, 2)));
+    assert_eq!(implementation_layout.get_static_slot(5), Some((300, 3)));
+    assert_eq!(implementation_layout.get_instance_slot(2), Some(1));
+}
@@ -0,0 +1,94 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ResolvedMethod {
+    pub class_name: usize,
+    pub method_name: usize,
+    pub method_descriptor: usize,
+    pub parameter_slot_count: usize,
+    // private methods are not overridden. so they are invoked without the virtual lookup
+    pub is_private: bool,
+    // the methods of the arrays are not looked up from the classes. e.g. clone of [I
+    pub is_array: bool,
+}
+
+// the constant pool indexes are per class. so the results of the resolution are kept per class
+#[derive(Debug)]
+pub struct ResolutionCache {
+    // constant pool index => (class_name which declares the field, slot)
+    fields: RefCell<Vec<Option<(usize, usize)>>>,
+    methods: RefCell<Vec<Option<ResolvedMethod>>>,
+    // (method_index, pc) => (receiver class_name, class_name which declares the method,
+    // index of the method in the declaring class which is None for the builtin classes)
+    inline_caches: RefCell<HashMap<(usize, usize), (usize, usize, Option<usize>)>>,
+}
+
+impl ResolutionCache {
+    pub fn new(constant_pool_count: usize) -> Self {
+        ResolutionCache {
+            fields: RefCell::new(vec![None; constant_pool_count]),
+            methods: RefCell::new(vec![None; constant_pool_count]),
+            inline_caches: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_field(&self, index: usize) -> Option<(usize, usize)> {
+        self.fields.borrow()[index]
+    }
+
+    pub fn set_field(&self, index: usize, field: (usize, usize)) {
+        self.fields.borrow_mut()[index] = Some(field);
+    }
+
+    pub fn get_method(&self, index: usize) -> Option<ResolvedMethod> {
+        self.methods.borrow()[index]
+    }
+
+    pub fn set_method(&self, index: usize, method: ResolvedMethod) {
+        self.methods.borrow_mut()[index] = Some(method);
+    }
+
+    // monomorphic. the cache misses when the receiver class is different from the last one
+    pub fn get_method_class(
+        &self,
+        call_site: (usize, usize),
+        receiver_class_name: usize,
+    ) -> Option<(usize, Option<usize>)> {
+        match self.inline_caches.borrow().get(&call_site) {
+            Some((cached_class_name, class_name, method_index))
+                if *cached_class_name == receiver_class_name =>
+            {
+                Some((*class_name, *method_index))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn set_method_class(
+        &self,
+        call_site: (usize, usize),
+        receiver_class_name: usize,
+        (class_name, method_index): (usize, Option<usize>),
+    ) {
+        self.inline_caches
+            .borrow_mut()
+            .insert(call_site, (receiver_class_name, class_name, method_index));
+    }
+}
+
+#[test]
+fn test_inline_cache() {
+    let cache = ResolutionCache::new(4);
+    assert_eq!(cache.get_method_class((0, 3), 10), None);
+
+    cache.set_method_class((0, 3), 10, (20, Some(2)));
+    assert_eq!(cache.get_method_class((0, 3), 10), Some((20, Some(2))));
+    assert_eq!(cache.get_method_class((0, 3), 11), None);
+    assert_eq!(cache.get_method_class((1, 3), 10), None);
+
+    // the entry is replaced by the new receiver class
+    cache.set_method_class((0, 3), 11, (21, None));
+    assert_eq!(cache.get_method_class((0, 3), 11), Some((21, None)));
+    assert_eq!(cache.get_method_class((0, 3), 10), None);
+}
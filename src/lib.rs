@@ -6,6 +6,7 @@ mod attribute;
 mod constant;
 mod context;
 mod field;
+mod gc;
 mod java_class;
 mod method;
 mod object;
@@ -32,7 +33,8 @@ use std::path::Path;
 #[macro_use]
 extern crate lazy_static;
 
-pub fn execute(file_name: String, debug_mode: usize) {
+// returns false if the exception is not caught in main
+pub fn execute(file_name: String, debug_mode: usize) -> bool {
     RJ_OPTION.lock().unwrap().debug_mode = debug_mode;
     let class_name = file_name + ".class";
     let buffer = get_file_content(&class_name);
@@ -45,10 +47,20 @@ pub fn execute(file_name: String, debug_mode: usize) {
         "./"
     };
 
-    let mut context = Context::new(&mut string_pool, class_map, &class_file, parent_path);
-    context.run_entry_file(&mut string_pool, class_file);
+    let mut context = Context::new(class_map, parent_path);
+    context.run_entry_file(&mut string_pool, class_file)
+}
+
+pub fn set_max_stack_depth(max_stack_depth: usize) {
+    RJ_OPTION.lock().unwrap().max_stack_depth = max_stack_depth;
 }
 
+pub fn set_max_heap_size(max_heap_size: usize) {
+    RJ_OPTION.lock().unwrap().max_heap_size = max_heap_size;
+}
+
+pub use crate::option::parse_memory_size;
+
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 pub fn run_wasm(class_name: &str) {
@@ -62,6 +74,6 @@ pub fn run_wasm(class_name: &str) {
     let class_map = setup_class_map(&mut string_pool);
     let parent_path = "";
 
-    let mut context = Context::new(&mut string_pool, class_map, &class_file, parent_path);
+    let mut context = Context::new(class_map, parent_path);
     context.run_entry_file(&mut string_pool, class_file);
 }
@@ -47,6 +47,20 @@ impl Method {
         )
     }
 
+    pub fn is_static(&self) -> bool {
+        self.access_flags
+            .0
+            .iter()
+            .any(|flag| *flag == MethodAccessFlag::AccStatic)
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.access_flags
+            .0
+            .iter()
+            .any(|flag| *flag == MethodAccessFlag::AccPrivate)
+    }
+
     pub fn extract_code<'a>(&self) -> Option<&Code> {
         if let Some(attribute) = self.attribute_info.iter().find(|attribute| {
             if let Attribute::Code(_) = attribute {
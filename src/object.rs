@@ -3,27 +3,49 @@ use crate::operand::Item;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
+use std::mem::size_of;
 
 #[derive(Debug)]
 pub struct ObjectMap {
     pub id: usize,
     pub map: HashMap<usize, Objectref>,
+    // the estimated bytes of the objects
+    pub size: usize,
 }
 impl ObjectMap {
     pub fn new() -> ObjectMap {
         ObjectMap {
             id: 0,
             map: HashMap::new(),
+            size: 0,
         }
     }
 
+    // the ids are not reused after the objects are collected
     pub fn add(&mut self, value: Objectref) -> usize {
         let id = self.id;
         self.id += 1;
+        self.size += value.size();
         self.map.insert(id, value);
         id
     }
 
+    // the objects which are not live are removed
+    pub fn retain<F>(&mut self, is_live: F)
+    where
+        F: Fn(&usize) -> bool,
+    {
+        let mut size = self.size;
+        self.map.retain(|id, object_ref| {
+            let is_live = is_live(id);
+            if !is_live {
+                size -= object_ref.size();
+            }
+            is_live
+        });
+        self.size = size;
+    }
+
     pub fn get(&self, id: &usize) -> Option<&Objectref> {
         self.map.get(id)
     }
@@ -33,35 +55,53 @@ impl ObjectMap {
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+// objects are compared by the id in ObjectMap. e.g. Item::Objectref(id)
+#[derive(Clone, Debug)]
 pub struct Objectref {
     pub class_name_id: usize,
-    pub field_map: RefCell<HashMap<(usize, usize), (Item, Item)>>,
-    pub is_initialized: bool,
+    pub fields: Fields,
 }
 
-pub type FieldMap = RefCell<HashMap<(usize, usize), (Item, Item)>>;
+// indexed by the slot of FieldLayout
+pub type Fields = RefCell<Vec<(Item, Item)>>;
+
+pub fn get_object_size(field_count: usize) -> usize {
+    size_of::<Objectref>() + field_count * size_of::<(Item, Item)>()
+}
 
 impl Objectref {
-    pub fn new(class_name_id: usize, field_map: FieldMap, is_initialized: bool) -> Objectref {
+    pub fn new(class_name_id: usize, fields: Fields) -> Objectref {
         Objectref {
             class_name_id,
-            field_map,
-            is_initialized,
+            fields,
         }
     }
+
+    // the number of the fields is not changed after the object is created
+    pub fn size(&self) -> usize {
+        get_object_size(self.fields.borrow().len())
+    }
+}
+
+// the object which is created by LambdaMetafactory.metafactory
+#[derive(Debug)]
+pub struct Lambda {
+    pub interface_method_name: usize,
+    pub reference_kind: usize,
+    pub class_name: usize,
+    pub method_name: usize,
+    pub method_descriptor: usize,
+    pub captured_items: Vec<Item>,
 }
 
 impl fmt::Display for Objectref {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let field_map = self.field_map.borrow();
-        let keys = field_map.keys();
-        let mut val_strs = Vec::with_capacity(keys.len());
-        for key in keys {
-            let val = field_map.get(key).unwrap();
+        let fields = self.fields.borrow();
+        let mut val_strs = Vec::with_capacity(fields.len());
+        for (slot, val) in fields.iter().enumerate() {
             match val.1 {
-                Item::Null => val_strs.push(format!("{}.{}: {}", key.0, key.1, val.0)),
-                _ => val_strs.push(format!("{}.{}: {} {}", key.0, key.1, val.0, val.1)),
+                Item::Null => val_strs.push(format!("{}: {}", slot, val.0)),
+                _ => val_strs.push(format!("{}: {} {}", slot, val.0, val.1)),
             };
         }
 
@@ -1,24 +1,14 @@
-// use crate::utils::devide_u64_to_two_u32;
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt;
 
-pub fn devide_i64_two_usize(input: i64) -> (usize, usize) {
-    let high_value = ((input >> 32) << 32) as usize;
-    let low_value = (input & 0xFFFFFFFF) as usize;
-    if input > 0 {
-        (low_value, high_value)
-    } else {
-        (0xFFFFFFFF - low_value, 0xFFFFFFFF - high_value)
-    }
-}
-
 #[derive(PartialEq, Clone, Debug)]
 pub enum Item {
     Null,
     Int(i32),
-    Long(usize),
+    // long and double use two slots. both slots have the whole value not to split it
+    Long(i64),
     Float(f32),
-    Double(usize),
+    Double(f64),
     String(usize),
     Boolean(bool),
     Classref(usize),
@@ -45,6 +35,15 @@ impl fmt::Display for Item {
     }
 }
 
+impl Item {
+    pub fn is_category_2(&self) -> bool {
+        match self {
+            Item::Long(_) | Item::Double(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl PartialOrd for Item {
     fn partial_cmp(&self, other: &Item) -> Option<Ordering> {
         match (self, other) {
@@ -59,7 +58,7 @@ impl PartialOrd for Item {
                     Ordering::Less
                 }
             }),
-            (Item::Double(left), Item::Double(right)) => Some(left.cmp(right)),
+            (Item::Double(left), Item::Double(right)) => left.partial_cmp(right),
             (Item::Boolean(left), Item::Boolean(right)) => Some(left.cmp(right)),
             (Item::Long(left), Item::Long(right)) => Some(left.cmp(right)),
             (Item::Classref(left), Item::Classref(right)) => Some(left.cmp(right)),
@@ -84,12 +83,39 @@ macro_rules! culculate {
     }
 }
 
+// int and long wrap around on overflow like Java
+macro_rules! culculate_wrapping {
+    ($name:ident, $extract_method:ident, $type:ident, $method:ident) => {
+        pub fn $name(&mut self) -> Item {
+            let (first, second) = self.$extract_method();
+            Item::$type(first.$method(second))
+        }
+    };
+}
+
+// for long and double which use two slots
+macro_rules! culculate_two_slots {
+    ($name:ident, $extract_method:ident, $type:ident, $op:tt) => {
+      pub fn $name(&mut self) -> (Item, Item) {
+          let (first, second) = self.$extract_method();
+          let value = first $op second;
+          (Item::$type(value), Item::$type(value))
+      }
+    }
+}
+
 impl OperandStack {
     pub fn new() -> Self {
         OperandStack { stack: vec![] }
     }
 
-    fn extract_long_values_as_i64(&mut self) -> (i64, i64) {
+    pub fn with_capacity(max_stack: usize) -> Self {
+        OperandStack {
+            stack: Vec::with_capacity(max_stack),
+        }
+    }
+
+    fn extract_long_values(&mut self) -> (i64, i64) {
         match (
             self.stack.pop(),
             self.stack.pop(),
@@ -97,25 +123,11 @@ impl OperandStack {
             self.stack.pop(),
         ) {
             (
-                Some(Item::Long(second_2)),
-                Some(Item::Long(second_1)),
-                Some(Item::Long(first_2)),
-                Some(Item::Long(first_1)),
-            ) => {
-                let second: u64 = (((second_1 as u64) << 32) as u64) | second_2 as u64;
-                let second = if second > 0x7fffffffffffffff {
-                    -1 * ((second ^ 0xffffffffffffffff) + 1) as i64
-                } else {
-                    second as i64
-                };
-                let first: u64 = (((first_1 as u64) << 32) as u64) | first_2 as u64;
-                let first = if first > 0x7fffffffffffffff {
-                    -1 * ((first ^ 0xffffffffffffffff) + 1) as i64
-                } else {
-                    first as i64
-                };
-                (first, second)
-            }
+                Some(Item::Long(second)),
+                Some(Item::Long(_)),
+                Some(Item::Long(first)),
+                Some(Item::Long(_)),
+            ) => (first, second),
             (second_2, second_1, first_2, first_1) => panic!(
                 "failed to extract long values
 first: {:?}, {:?}
@@ -125,6 +137,28 @@ second: {:?}, {:?}",
         }
     }
 
+    fn extract_double_values(&mut self) -> (f64, f64) {
+        match (
+            self.stack.pop(),
+            self.stack.pop(),
+            self.stack.pop(),
+            self.stack.pop(),
+        ) {
+            (
+                Some(Item::Double(second)),
+                Some(Item::Double(_)),
+                Some(Item::Double(first)),
+                Some(Item::Double(_)),
+            ) => (first, second),
+            (second_2, second_1, first_2, first_1) => panic!(
+                "failed to extract double values
+first: {:?}, {:?}
+second: {:?}, {:?}",
+                first_1, first_2, second_1, second_2
+            ),
+        }
+    }
+
     fn extract_int_values(&mut self) -> (i32, i32) {
         match (self.stack.pop(), self.stack.pop()) {
             (Some(Item::Int(second)), Some(Item::Int(first))) => (first, second),
@@ -137,6 +171,36 @@ second: {:?}",
         }
     }
 
+    // long and double use two slots, so dup and pop families can be handled per slot
+    // copy `count` slots on the top and insert them under `depth` slots
+    pub fn dup_x(&mut self, count: usize, depth: usize) {
+        let length = self.stack.len();
+        if length < count + depth {
+            panic!(
+                "failed to dup {} slots under {} slots: {:?}",
+                count, depth, self.stack
+            );
+        }
+        let values = self.stack[length - count..].to_vec();
+        let insert_index = length - count - depth;
+        self.stack.splice(insert_index..insert_index, values);
+    }
+
+    // value2 of lshl, lshr and lushr is int
+    fn extract_long_and_int_values(&mut self) -> (i64, i32) {
+        match (self.stack.pop(), self.stack.pop(), self.stack.pop()) {
+            (Some(Item::Int(second)), Some(Item::Long(first)), Some(Item::Long(_))) => {
+                (first, second)
+            }
+            (second, first_2, first_1) => panic!(
+                "failed to extract long and int values
+first: {:?}, {:?}
+second: {:?}",
+                first_1, first_2, second
+            ),
+        }
+    }
+
     fn extract_float_values(&mut self) -> (f32, f32) {
         match (self.stack.pop(), self.stack.pop()) {
             (Some(Item::Float(second)), Some(Item::Float(first))) => (first, second),
@@ -149,11 +213,14 @@ second: {:?}",
         }
     }
 
-    culculate!(iadd, extract_int_values, Int, +);
-    culculate!(isub, extract_int_values, Int, -);
-    culculate!(imul, extract_int_values, Int, *);
-    culculate!(idiv, extract_int_values, Int, /);
-    culculate!(irem, extract_int_values, Int, %);
+    culculate_wrapping!(iadd, extract_int_values, Int, wrapping_add);
+    culculate_wrapping!(isub, extract_int_values, Int, wrapping_sub);
+    culculate_wrapping!(imul, extract_int_values, Int, wrapping_mul);
+    culculate_wrapping!(idiv, extract_int_values, Int, wrapping_div);
+    culculate_wrapping!(irem, extract_int_values, Int, wrapping_rem);
+    culculate!(iand, extract_int_values, Int, &);
+    culculate!(ior, extract_int_values, Int, |);
+    culculate!(ixor, extract_int_values, Int, ^);
 
     culculate!(fadd, extract_float_values, Float, +);
     culculate!(fsub, extract_float_values, Float, -);
@@ -161,41 +228,119 @@ second: {:?}",
     culculate!(fdiv, extract_float_values, Float, /);
     culculate!(frem, extract_float_values, Float, %);
 
+    culculate_two_slots!(dadd, extract_double_values, Double, +);
+    culculate_two_slots!(dsub, extract_double_values, Double, -);
+    culculate_two_slots!(dmul, extract_double_values, Double, *);
+    culculate_two_slots!(ddiv, extract_double_values, Double, /);
+    culculate_two_slots!(drem, extract_double_values, Double, %);
+
     pub fn ladd(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first + second);
-        (Item::Long(first), Item::Long(second))
+        let (first, second) = self.extract_long_values();
+        let value = first.wrapping_add(second);
+        (Item::Long(value), Item::Long(value))
     }
 
     pub fn lsub(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first + second);
-        (Item::Long(first), Item::Long(second))
+        let (first, second) = self.extract_long_values();
+        let value = first.wrapping_sub(second);
+        (Item::Long(value), Item::Long(value))
     }
 
     pub fn lmul(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first * second);
-        (Item::Long(first), Item::Long(second))
+        let (first, second) = self.extract_long_values();
+        let value = first.wrapping_mul(second);
+        (Item::Long(value), Item::Long(value))
     }
 
     pub fn ldiv(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first / second);
-        (Item::Long(first), Item::Long(second))
+        let (first, second) = self.extract_long_values();
+        let value = first.wrapping_div(second);
+        (Item::Long(value), Item::Long(value))
     }
 
     pub fn lrem(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first % second);
-        (Item::Long(first), Item::Long(second))
+        let (first, second) = self.extract_long_values();
+        let value = first.wrapping_rem(second);
+        (Item::Long(value), Item::Long(value))
+    }
+
+    culculate_two_slots!(land, extract_long_values, Long, &);
+    culculate_two_slots!(lor, extract_long_values, Long, |);
+    culculate_two_slots!(lxor, extract_long_values, Long, ^);
+
+    // only the low 5 bits of the shift distance are used for int, and the low 6 bits for long
+    pub fn ishl(&mut self) -> Item {
+        let (first, second) = self.extract_int_values();
+        Item::Int(first << (second & 0x1f))
+    }
+
+    pub fn ishr(&mut self) -> Item {
+        let (first, second) = self.extract_int_values();
+        Item::Int(first >> (second & 0x1f))
+    }
+
+    pub fn iushr(&mut self) -> Item {
+        let (first, second) = self.extract_int_values();
+        Item::Int(((first as u32) >> (second & 0x1f)) as i32)
+    }
+
+    pub fn lshl(&mut self) -> (Item, Item) {
+        let (first, second) = self.extract_long_and_int_values();
+        let value = first << (second & 0x3f);
+        (Item::Long(value), Item::Long(value))
+    }
+
+    pub fn lshr(&mut self) -> (Item, Item) {
+        let (first, second) = self.extract_long_and_int_values();
+        let value = first >> (second & 0x3f);
+        (Item::Long(value), Item::Long(value))
+    }
+
+    pub fn lushr(&mut self) -> (Item, Item) {
+        let (first, second) = self.extract_long_and_int_values();
+        let value = ((first as u64) >> (second & 0x3f)) as i64;
+        (Item::Long(value), Item::Long(value))
+    }
+
+    pub fn ineg(&mut self) -> Item {
+        match self.stack.pop() {
+            Some(Item::Int(value)) => Item::Int(value.wrapping_neg()),
+            item => panic!("failed to extract int value: {:?}", item),
+        }
+    }
+
+    pub fn fneg(&mut self) -> Item {
+        match self.stack.pop() {
+            Some(Item::Float(value)) => Item::Float(-value),
+            item => panic!("failed to extract float value: {:?}", item),
+        }
+    }
+
+    pub fn lneg(&mut self) -> (Item, Item) {
+        match (self.stack.pop(), self.stack.pop()) {
+            (Some(Item::Long(value)), Some(Item::Long(_))) => {
+                let value = value.wrapping_neg();
+                (Item::Long(value), Item::Long(value))
+            }
+            items => panic!("failed to extract long value: {:?}", items),
+        }
     }
 
     pub fn lcmp(&mut self) -> Item {
-        let (first, second) = self.extract_long_values_as_i64();
+        let (first, second) = self.extract_long_values();
         self.compare_value(first, second)
     }
 
+    // dcmpl pushes -1 and dcmpg pushes 1 if either value is NaN
+    pub fn dcmp(&mut self, nan_result: i32) -> Item {
+        let (first, second) = self.extract_double_values();
+        if first.is_nan() || second.is_nan() {
+            Item::Int(nan_result)
+        } else {
+            self.compare_value(first, second)
+        }
+    }
+
     pub fn fcmp(&mut self) -> Item {
         let (first, second) = self.extract_float_values();
         self.compare_value(first, second)
@@ -220,15 +365,14 @@ impl fmt::Display for OperandStack {
         let length = self.stack.len();
         let mut item_string_vec = Vec::with_capacity(length);
         let mut index = 0;
-        for item in self.stack.iter() {
-            match item {
-                Item::Long(_) => {
-                    item_string_vec.push(format!("#{}+#{} {}", index, index + 1, item));
-                    index += 1;
-                }
-                _ => item_string_vec.push(format!("#{} {}", index, item)),
-            };
-            index += 1;
+        while let Some(item) = self.stack.get(index) {
+            if item.is_category_2() {
+                item_string_vec.push(format!("#{}+#{} {}", index, index + 1, item));
+                index += 2;
+            } else {
+                item_string_vec.push(format!("#{} {}", index, item));
+                index += 1;
+            }
         }
 
         write!(
@@ -242,3 +386,27 @@ item ====================
         )
     }
 }
+
+#[test]
+fn test_dup_x() {
+    let mut operand_stack = OperandStack::new();
+    operand_stack.stack = vec![Item::Int(1), Item::Long(2), Item::Long(2)];
+    operand_stack.dup_x(2, 1);
+    assert_eq!(
+        operand_stack.stack,
+        vec![
+            Item::Long(2),
+            Item::Long(2),
+            Item::Int(1),
+            Item::Long(2),
+            Item::Long(2)
+        ]
+    );
+
+    operand_stack.stack = vec![Item::Int(1), Item::Int(2), Item::Int(3)];
+    operand_stack.dup_x(1, 2);
+    assert_eq!(
+        operand_stack.stack,
+        vec![Item::Int(3), Item::Int(1), Item::Int(2), Item::Int(3)]
+    );
+}
@@ -8,14 +8,52 @@ use std::sync::Mutex;
 #[derive(Debug)]
 pub struct RjOption {
     pub debug_mode: usize,
+    // StackOverflowError is thrown when the number of the frames exceeds it
+    pub max_stack_depth: usize,
+    // bytes. same as -Xmx
+    pub max_heap_size: usize,
 }
 
 impl RjOption {
     pub fn new() -> RjOption {
-        RjOption { debug_mode: 0 }
+        RjOption {
+            debug_mode: 0,
+            max_stack_depth: DEFAULT_MAX_STACK_DEPTH,
+            max_heap_size: DEFAULT_MAX_HEAP_SIZE,
+        }
     }
 }
 
+pub const DEFAULT_MAX_STACK_DEPTH: usize = 10000;
+pub const DEFAULT_MAX_HEAP_SIZE: usize = 256 * 1024 * 1024;
+
+// same format as -Xmx. e.g. 1024, 64k, 16m, 1g
+pub fn parse_memory_size(value: &str) -> Option<usize> {
+    let unit = match value.chars().last()? {
+        'k' | 'K' => 1024,
+        'm' | 'M' => 1024 * 1024,
+        'g' | 'G' => 1024 * 1024 * 1024,
+        _ => 1,
+    };
+    let digits = if unit == 1 {
+        value
+    } else {
+        &value[..value.len() - 1]
+    };
+    digits.parse::<usize>().ok()?.checked_mul(unit)
+}
+
 lazy_static! {
     pub static ref RJ_OPTION: Mutex<RjOption> = Mutex::new(RjOption::new());
 }
+
+#[test]
+fn test_parse_memory_size() {
+    assert_eq!(parse_memory_size("1024"), Some(1024));
+    assert_eq!(parse_memory_size("64k"), Some(64 * 1024));
+    assert_eq!(parse_memory_size("16M"), Some(16 * 1024 * 1024));
+    assert_eq!(parse_memory_size("1g"), Some(1024 * 1024 * 1024));
+    assert_eq!(parse_memory_size("m"), None);
+    assert_eq!(parse_memory_size("12x"), None);
+    assert_eq!(parse_memory_size(""), None);
+}
@@ -1,16 +1,43 @@
+use crate::java_class::custom::Custom;
 use crate::operand::{Item, OperandStack};
 
+use std::rc::Rc;
+
 #[derive(Debug)]
 pub struct Stackframe {
     pub local_variables: Vec<Item>,
     pub operand_stack: OperandStack,
+    // the class and the index of the method which runs on this frame. None for builtin methods
+    pub method: Option<(Rc<Custom>, usize)>,
+    // the index of the instruction which invokes the next frame
+    pub pc: usize,
+    // the index of the instruction which is executed after the invoked method returns
+    pub return_pc: usize,
+    // e.g. the result of the lambda is discarded for Runnable
+    pub discards_return_value: bool,
+    // the class which is initialized by <clinit> on this frame
+    pub initializing_class: Option<usize>,
+    // the result replaces the item at this index of the caller's operand stack. e.g. toString
+    pub return_slot: Option<usize>,
 }
 
 impl Stackframe {
-    pub fn new(variables_number: usize) -> Self {
+    // the local variables are filled with Null until they are stored
+    pub fn new(max_locals: usize, max_stack: usize) -> Self {
         Stackframe {
-            local_variables: Vec::with_capacity(variables_number),
-            operand_stack: OperandStack::new(),
+            local_variables: vec![Item::Null; max_locals],
+            operand_stack: OperandStack::with_capacity(max_stack),
+            method: None,
+            pc: 0,
+            return_pc: 0,
+            discards_return_value: false,
+            initializing_class: None,
+            return_slot: None,
         }
     }
+
+    // the instruction which invokes this frame is executed again after this frame returns
+    pub fn retries_caller_instruction(&self) -> bool {
+        self.initializing_class.is_some() || self.return_slot.is_some()
+    }
 }
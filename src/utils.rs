@@ -1,11 +1,10 @@
+use crate::array::PrimitiveArrayType;
 use crate::attribute::instruction::Instruction;
-use crate::object::{ObjectMap, Objectref};
 use crate::operand::Item;
 use crate::option::RJ_OPTION;
 use crate::stackframe::Stackframe;
 
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::Result;
@@ -35,8 +34,34 @@ pub fn extract_x_byte_as_usize(input: &[u8], index: usize, x: usize) -> (usize,
     (result, index + x)
 }
 
-pub fn devide_i64_to_two_i32(input: i64) -> (i32, i32) {
-    (((input >> 32) << 32) as i32, (input & 0xFFFFFFFF) as i32)
+// same format as Double.toString. e.g. 1.0, 1.5, 1.0E20, 1.0E-5, NaN, Infinity
+pub fn format_floating_point<T>(value: T) -> String
+where
+    T: fmt::Display + fmt::LowerExp + Into<f64> + Copy,
+{
+    let float_value: f64 = value.into();
+    if float_value.is_nan() {
+        String::from("NaN")
+    } else if float_value.is_infinite() {
+        if float_value > 0.0 {
+            String::from("Infinity")
+        } else {
+            String::from("-Infinity")
+        }
+    } else if float_value != 0.0 && !(1e-3..1e7).contains(&float_value.abs()) {
+        // the computerized scientific notation. e.g. 1.23456789E8
+        let formatted = format!("{:e}", value);
+        let (mantissa, exponent) = formatted.split_once('e').expect("should exist exponent");
+        if mantissa.contains('.') {
+            format!("{}E{}", mantissa, exponent)
+        } else {
+            format!("{}.0E{}", mantissa, exponent)
+        }
+    } else if float_value.fract() == 0.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("{}", value)
+    }
 }
 
 pub fn emit_debug_info(instruction: &Instruction, stackframe: Option<&Stackframe>) {
@@ -62,16 +87,16 @@ pub fn iniailize_primitive_array(type_index: usize, length: usize) -> Vec<(Item,
     let default_val = match type_index {
         // TBoolean
         4 => (Item::Boolean(false), Item::Null),
-        // // TChar
-        // 5 => ,
-        // // TFloat
-        // 6 => ,
-        // // TDouble
-        // 7 => ,
-        // // TByte
-        // 8 => ,
-        // // TShort
-        // 9 => ,
+        // TChar
+        5 => (Item::Int(0), Item::Null),
+        // TFloat
+        6 => (Item::Float(0.0), Item::Null),
+        // TDouble
+        7 => (Item::Double(0.0), Item::Double(0.0)),
+        // TByte
+        8 => (Item::Int(0), Item::Null),
+        // TShort
+        9 => (Item::Int(0), Item::Null),
         // TInt
         10 => (Item::Int(0), Item::Null),
         // TLong
@@ -83,22 +108,35 @@ pub fn iniailize_primitive_array(type_index: usize, length: usize) -> Vec<(Item,
     initialize_vec
 }
 
-pub fn initialize_objectref_array(
-    object_map: &mut ObjectMap,
-    class_name_id: usize,
-    length: usize,
-) -> Vec<usize> {
-    let mut initialize_vec = Vec::with_capacity(length);
-    for _ in 0..length {
-        let id = object_map.add(Objectref::new(
-            class_name_id,
-            RefCell::new(HashMap::new()),
-            false,
-        ));
-        initialize_vec.push(id);
-    }
+// e.g. I => 10
+pub fn get_primitive_array_type(descriptor: &str) -> usize {
+    let primitive_array_type = match descriptor {
+        "Z" => PrimitiveArrayType::TBoolean,
+        "C" => PrimitiveArrayType::TChar,
+        "F" => PrimitiveArrayType::TFloat,
+        "D" => PrimitiveArrayType::TDouble,
+        "B" => PrimitiveArrayType::TByte,
+        "S" => PrimitiveArrayType::TShort,
+        "I" => PrimitiveArrayType::TInt,
+        "J" => PrimitiveArrayType::TLong,
+        _ => unreachable!("{} is not a primitive type", descriptor),
+    };
+    primitive_array_type as usize
+}
 
-    initialize_vec
+// e.g. 10 => I
+pub fn get_primitive_array_descriptor(type_index: usize) -> &'static str {
+    match type_index {
+        4 => "Z",
+        5 => "C",
+        6 => "F",
+        7 => "D",
+        8 => "B",
+        9 => "S",
+        10 => "I",
+        11 => "J",
+        _ => unreachable!("type_index range should 4 - 11"),
+    }
 }
 
 #[macro_export]
@@ -143,3 +181,17 @@ pub fn test_extract_x_byte_as_usize() {
     );
     assert_eq!(input[4], 5);
 }
+
+#[test]
+pub fn test_format_floating_point() {
+    assert_eq!(format_floating_point(1.0), "1.0");
+    assert_eq!(format_floating_point(-0.0), "-0.0");
+    assert_eq!(format_floating_point(0.001), "0.001");
+    assert_eq!(format_floating_point(1234567.5), "1234567.5");
+    assert_eq!(format_floating_point(1e20), "1.0E20");
+    assert_eq!(format_floating_point(1e-5), "1.0E-5");
+    assert_eq!(format_floating_point(123456789.0), "1.23456789E8");
+    assert_eq!(format_floating_point(-1e7), "-1.0E7");
+    assert_eq!(format_floating_point(1.5e-4f32), "1.5E-4");
+    assert_eq!(format_floating_point(f64::NAN), "NaN");
+}
@@ -57,3 +57,13 @@ pub fn print_log(value: &str) {
 pub fn print_log(value: &str) {
     output_log(value);
 }
+
+#[cfg(unix)]
+pub fn print_error(value: &str) {
+    eprintln!("{}", value);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn print_error(value: &str) {
+    output_log(value);
+}
@@ -0,0 +1,41 @@
+class Bitwise {
+    public static void main(String[] args) {
+        int a = 0x5a;
+        int b = -16;
+        long l = 0x0f0f0f0f0f0fL;
+
+        System.out.println(a & b);
+        System.out.println(a | b);
+        System.out.println(a ^ b);
+        System.out.println(a << 3);
+        System.out.println(b >> 2);
+        System.out.println(b >>> 28);
+        System.out.println(a << 33);
+        System.out.println(b >>> 36);
+        System.out.println(1 << a);
+        System.out.println(-a);
+        a = 1;
+        a <<= 31;
+        System.out.println(-a);
+
+        System.out.println(l & 0xff00ff00ff00L);
+        System.out.println(l | 0xf0L);
+        System.out.println(l ^ -1L);
+        System.out.println(l << 20);
+        System.out.println(-l >> 8);
+        System.out.println(-l >>> 8);
+        System.out.println(l << 65);
+        System.out.println(-l >>> b);
+        l = Long.MIN_VALUE;
+        System.out.println(-l);
+
+        negateFloat();
+    }
+
+    static void negateFloat() {
+        float f = 1.5f;
+        System.out.println(-f);
+        f = 0.0f;
+        System.out.println(-f);
+    }
+}
@@ -0,0 +1,121 @@
+class InitParent {
+    static int parentValue = initParent();
+
+    static int initParent() {
+        System.out.println("InitParent <clinit>");
+        return 10;
+    }
+}
+
+class InitChild extends InitParent {
+    static int childValue = InitParent.parentValue + 5;
+
+    static {
+        System.out.println("InitChild <clinit>");
+    }
+}
+
+class Recursive {
+    static int first = 1;
+    static int second = Recursive.first + 1;
+
+    static {
+        System.out.println("Recursive <clinit>");
+    }
+}
+
+class Broken {
+    static int value = fail();
+
+    static int fail() {
+        throw new IllegalStateException("broken initializer");
+    }
+}
+
+class Lazy {
+    static {
+        System.out.println("Lazy <clinit>");
+    }
+
+    static void touch() {
+        System.out.println("Lazy.touch");
+    }
+}
+
+class BrokenBase {
+    static int value = fail();
+
+    static int fail() {
+        throw new IllegalArgumentException("broken base");
+    }
+}
+
+class BrokenMiddle extends BrokenBase {
+    static {
+        System.out.println("BrokenMiddle <clinit>");
+    }
+}
+
+class BrokenLeaf extends BrokenMiddle {
+    static int value = 3;
+}
+
+class LabelCounter {
+    static int count = 4;
+
+    static {
+        System.out.println("LabelCounter <clinit>");
+    }
+}
+
+class Label {
+    public String toString() {
+        return "label " + LabelCounter.count;
+    }
+}
+
+class Described {
+    static String description = "described by " + new Label();
+}
+
+public class ClassInit {
+    public static void main(String[] args) {
+        System.out.println("main start");
+        System.out.println(InitChild.childValue);
+        System.out.println(InitParent.parentValue);
+        System.out.println(Recursive.second);
+
+        try {
+            System.out.println(Broken.value);
+        } catch (ExceptionInInitializerError e) {
+            System.out.println("caught ExceptionInInitializerError");
+            System.out.println(e.getCause().getMessage());
+        }
+        try {
+            System.out.println(Broken.value);
+        } catch (NoClassDefFoundError e) {
+            System.out.println(e.getMessage());
+        }
+
+        try {
+            System.out.println(BrokenLeaf.value);
+        } catch (ExceptionInInitializerError e) {
+            System.out.println("caught " + e.getCause().getMessage());
+        }
+        try {
+            new BrokenMiddle();
+        } catch (NoClassDefFoundError e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            System.out.println(BrokenLeaf.value);
+        } catch (NoClassDefFoundError e) {
+            System.out.println(e.getMessage());
+        }
+
+        System.out.println(Described.description);
+        System.out.println("before Lazy");
+        Lazy.touch();
+        Lazy.touch();
+    }
+}
@@ -0,0 +1,51 @@
+// compiled against Settings whose fields are not constant variables.
+// so the values are read by getstatic from the ConstantValue attributes
+public class ConstantFields {
+    static boolean flag;
+    static byte smallNumber;
+    static char letter;
+    static short shortNumber;
+    static float ratio;
+    static double rate;
+    static Object object;
+    static int[] numbers;
+
+    boolean instanceFlag;
+    char instanceLetter;
+    float instanceRatio;
+    String instanceName;
+    long[] instanceNumbers;
+
+    public static void main(String[] args) {
+        System.out.println(Settings.MAX_SIZE);
+        System.out.println(Settings.NEGATIVE);
+        System.out.println(Settings.TIMEOUT);
+        System.out.println(Settings.RATIO);
+        System.out.println(Settings.PI);
+        System.out.println(Settings.NAME);
+        System.out.println("enabled=" + Settings.ENABLED);
+        System.out.println("separator=" + Settings.SEPARATOR);
+        System.out.println(Settings.LEVEL);
+        System.out.println(Settings.PORT);
+
+        System.out.println("flag=" + flag);
+        System.out.println(smallNumber);
+        System.out.println((int) letter);
+        System.out.println(shortNumber);
+        System.out.println(ratio);
+        System.out.println(rate);
+        System.out.println(object == null ? "null object" : "object");
+        System.out.println(numbers == null ? "null array" : "array");
+
+        ConstantFields fields = new ConstantFields();
+        System.out.println("instanceFlag=" + fields.instanceFlag);
+        System.out.println((int) fields.instanceLetter);
+        System.out.println(fields.instanceRatio);
+        System.out.println(fields.instanceName);
+        System.out.println(fields.instanceNumbers == null ? "null array" : "array");
+
+        flag = true;
+        letter = 'x';
+        System.out.println("flag=" + flag + " letter=" + letter);
+    }
+}
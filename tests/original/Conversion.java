@@ -0,0 +1,46 @@
+class Conversion {
+    public static void main(String[] args) {
+        float f = 3.99f;
+        int i = 200;
+        long l = 10000000000L;
+        double d = 1e10;
+
+        System.out.println((byte) i);
+        System.out.println((short) (i * i));
+        i = -1;
+        System.out.println((int) (char) i);
+        System.out.println((long) i);
+        System.out.println((float) i);
+        System.out.println((double) i);
+
+        System.out.println((int) l);
+        l = -5L;
+        System.out.println((float) l);
+        System.out.println((double) l);
+
+        System.out.println((int) f);
+        f = -3.99f;
+        System.out.println((int) f);
+        System.out.println((long) f);
+        System.out.println((double) f);
+        f = 1e20f;
+        System.out.println((int) f);
+        System.out.println((long) f);
+        f = 0.0f / 0.0f;
+        System.out.println((int) f);
+        System.out.println((long) f);
+
+        System.out.println((int) d);
+        System.out.println((long) d);
+        d = -2.5;
+        System.out.println((int) d);
+        System.out.println((long) d);
+        d = 0.1;
+        System.out.println((float) d);
+        d = -1e19;
+        System.out.println((long) d);
+        d = 0.0 / 0.0;
+        System.out.println((int) d);
+        System.out.println((long) d);
+    }
+}
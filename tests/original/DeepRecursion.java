@@ -0,0 +1,147 @@
+class TreeNode {
+    int value;
+    TreeNode left;
+    TreeNode right;
+
+    TreeNode(int value) {
+        this.value = value;
+    }
+
+    void insert(int newValue) {
+        if (newValue < value) {
+            if (left == null) {
+                left = new TreeNode(newValue);
+            } else {
+                left.insert(newValue);
+            }
+        } else {
+            if (right == null) {
+                right = new TreeNode(newValue);
+            } else {
+                right.insert(newValue);
+            }
+        }
+    }
+
+    int height() {
+        int leftHeight = left == null ? 0 : left.height();
+        int rightHeight = right == null ? 0 : right.height();
+        return Math.max(leftHeight, rightHeight) + 1;
+    }
+
+    long sum() {
+        long result = value;
+        if (left != null) {
+            result += left.sum();
+        }
+        if (right != null) {
+            result += right.sum();
+        }
+        return result;
+    }
+
+    public String toString() {
+        String leftText = left == null ? "" : left.toString();
+        String rightText = right == null ? "" : right.toString();
+        return "(" + leftText + value + rightText + ")";
+    }
+}
+
+class Chain {
+    Chain next;
+    int depth;
+
+    public String toString() {
+        if (next == null) {
+            return "end";
+        }
+        return depth % 500 == 0 ? "<" + next + ">" : "" + next;
+    }
+}
+
+class Loop {
+    public String toString() {
+        return "loop " + this;
+    }
+}
+
+public class DeepRecursion {
+    static int depth = 0;
+    static int bumps = 0;
+
+    static int sumTo(int n) {
+        if (n == 0) {
+            return 0;
+        }
+        return n + sumTo(n - 1);
+    }
+
+    static void infinite() {
+        depth++;
+        infinite();
+    }
+
+    static int bump() {
+        bumps++;
+        return bumps;
+    }
+
+    static int throwAt(int n) {
+        if (n == 0) {
+            throw new IllegalStateException("bottom");
+        }
+        return throwAt(n - 1) + 1;
+    }
+
+    public static void main(String[] args) {
+        System.out.println(sumTo(5000));
+
+        TreeNode root = new TreeNode(0);
+        for (int i = 1; i < 3000; i++) {
+            root.insert(i);
+        }
+        System.out.println(root.height());
+        System.out.println(root.sum());
+
+        TreeNode small = new TreeNode(4);
+        small.insert(2);
+        small.insert(6);
+        small.insert(1);
+        small.insert(5);
+        System.out.println("tree: " + small);
+
+        try {
+            infinite();
+        } catch (StackOverflowError e) {
+            System.out.println("caught StackOverflowError " + (depth > 1000));
+        }
+        System.out.println(sumTo(100));
+
+        try {
+            throwAt(2000);
+        } catch (IllegalStateException e) {
+            System.out.println("caught " + e.getMessage());
+        }
+
+        Chain chain = new Chain();
+        for (int i = 0; i < 2000; i++) {
+            Chain head = new Chain();
+            head.next = chain;
+            head.depth = i + 1;
+            chain = head;
+        }
+        System.out.println("chain: " + chain);
+        System.out.println(String.valueOf(chain));
+
+        try {
+            System.out.println("loop: " + new Loop());
+        } catch (StackOverflowError e) {
+            System.out.println("caught StackOverflowError in toString");
+        }
+
+        Runnable runnable = DeepRecursion::bump;
+        runnable.run();
+        runnable.run();
+        System.out.println(bumps);
+    }
+}
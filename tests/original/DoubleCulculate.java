@@ -0,0 +1,45 @@
+public class DoubleCulculate {
+  static double scale = 0.5;
+
+  public static void main(String[] args) {
+    double a = 0.1;
+    double b = 0.2;
+    double c = a + b;
+    System.out.println(c);
+    System.out.println(a - b);
+    System.out.println(a * b);
+    System.out.println(b / a);
+
+    double x = 7.5;
+    System.out.println(x % b);
+    System.out.println(x % 2.0);
+    System.out.println(-x % 2.0);
+
+    double zero = 0.0;
+    System.out.println(x / zero);
+    System.out.println(-x / zero);
+    System.out.println(zero / zero);
+    if (zero / zero > a) {
+      System.out.println("NaN is greater");
+    }
+    if (zero / zero < a) {
+      System.out.println("NaN is less");
+    }
+    if (a < b) {
+      System.out.println("a is less");
+    }
+    if (a >= b) {
+      System.out.println("a is greater or equal");
+    }
+
+    double[] values = new double[3];
+    values[1] = x;
+    values[2] = values[0] + values[1] * scale;
+    System.out.println(values[2]);
+    System.out.println(average(a, x));
+  }
+
+  static double average(double left, double right) {
+    return (left + right) / 2;
+  }
+}
@@ -0,0 +1,120 @@
+enum Planet {
+    MERCURY(3, 0.38),
+    EARTH(6, 1.0),
+    JUPITER(69, 2.53);
+
+    private final int radius;
+    private final double gravity;
+
+    Planet(int radius, double gravity) {
+        this.radius = radius;
+        this.gravity = gravity;
+    }
+
+    int radius() {
+        return radius;
+    }
+
+    double weight(double mass) {
+        return mass * gravity;
+    }
+}
+
+enum Operation {
+    PLUS {
+        int apply(int left, int right) {
+            return left + right;
+        }
+    },
+    TIMES {
+        int apply(int left, int right) {
+            return left * right;
+        }
+    };
+
+    abstract int apply(int left, int right);
+}
+
+class EnumHolder {
+    enum Color {
+        RED,
+        GREEN
+    }
+}
+
+public class EnumBasics {
+    public static void main(String[] args) {
+        Planet[] planets = Planet.values();
+        System.out.println(planets.length);
+        for (Planet planet : planets) {
+            System.out.println(planet.ordinal() + " " + planet.name() + " " + planet.radius());
+        }
+        System.out.println("earth weight " + Planet.EARTH.weight(50.0));
+        System.out.println("toString " + Planet.JUPITER.toString());
+        System.out.println("concat " + Planet.MERCURY);
+
+        Planet earth = Planet.valueOf("EARTH");
+        System.out.println(earth == Planet.EARTH ? "same constant" : "different constant");
+        System.out.println(Planet.MERCURY.compareTo(Planet.JUPITER));
+        System.out.println(Planet.JUPITER.compareTo(Planet.EARTH));
+        try {
+            Planet.valueOf("PLUTO");
+        } catch (IllegalArgumentException e) {
+            System.out.println(e.getMessage());
+        }
+
+        Planet[] copied = Planet.values();
+        copied[0] = Planet.JUPITER;
+        System.out.println(Planet.values()[0].name());
+
+        for (Operation operation : Operation.values()) {
+            System.out.println(operation + " " + operation.apply(6, 7));
+        }
+        System.out.println(Operation.valueOf("TIMES").apply(3, 4));
+
+        System.out.println("equals " + Planet.EARTH.equals(earth) + " " + Planet.EARTH.equals(Planet.MERCURY));
+        System.out.println("equals body " + Operation.PLUS.equals(Operation.valueOf("PLUS")));
+        System.out.println("hashCode " + (earth.hashCode() == Planet.EARTH.hashCode()));
+        EnumHolder.Color color = EnumHolder.Color.GREEN;
+        System.out.println("nested " + color.equals(EnumHolder.Color.valueOf("GREEN")));
+        try {
+            EnumHolder.Color.valueOf("BLUE");
+        } catch (IllegalArgumentException e) {
+            System.out.println(e.getMessage());
+        }
+
+        // equals and hashCode are inherited from java/lang/Object
+        Object box = new EnumPlainBox(1);
+        Object other = new EnumPlainBox(1);
+        System.out.println("object equals " + box.equals(box) + " " + box.equals(other));
+        System.out.println("object hashCode " + (box.hashCode() == box.hashCode()));
+        EnumPlainBox typed = new EnumPlainBox(2);
+        System.out.println("typed equals " + typed.equals(typed) + " " + typed.equals(null));
+        System.out.println("super equals " + new EnumSameBox(3).equals(new EnumSameBox(3)));
+    }
+}
+
+class EnumPlainBox {
+    int value;
+
+    EnumPlainBox(int value) {
+        this.value = value;
+    }
+}
+
+class EnumSameBox extends EnumPlainBox {
+    EnumSameBox(int value) {
+        super(value);
+    }
+
+    public boolean equals(Object other) {
+        if (super.equals(other)) {
+            return true;
+        }
+        return other instanceof EnumSameBox && ((EnumSameBox) other).value == value;
+    }
+
+    public int hashCode() {
+        return value;
+    }
+}
@@ -0,0 +1,81 @@
+public class ExceptionHandling {
+  public static void main(String[] args) {
+    try {
+      throw new RuntimeException("thrown directly");
+    } catch (RuntimeException e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      throwDeep(3);
+    } catch (IllegalArgumentException e) {
+      System.out.println("not reached");
+    } catch (Exception e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      System.out.println("try");
+    } finally {
+      System.out.println("finally");
+    }
+
+    System.out.println(finallyWithThrow());
+
+    try {
+      try {
+        throw new IllegalArgumentException("inner");
+      } catch (IllegalArgumentException e) {
+        throw new RuntimeException("wrap", e);
+      }
+    } catch (RuntimeException e) {
+      System.out.println(e.getMessage() + " caused by " + e.getCause().getMessage());
+    }
+
+    try {
+      throw new IllegalStateException(new ArithmeticException("zero"));
+    } catch (IllegalStateException e) {
+      System.out.println(e.getMessage());
+      System.out.println(e.getCause().toString());
+    }
+
+    try {
+      throw new CustomException("custom", new NullPointerException());
+    } catch (CustomException e) {
+      System.out.println(e.getMessage() + " caused by " + e.getCause().toString());
+    }
+    System.out.println();
+    throw new IllegalStateException("uncaught");
+  }
+
+  static void throwDeep(int depth) throws CustomException {
+    if (depth == 0) {
+      throw new CustomException("thrown from deep");
+    }
+    throwDeep(depth - 1);
+  }
+
+  static int finallyWithThrow() {
+    int result = 1;
+    try {
+      try {
+        throw new ArithmeticException();
+      } finally {
+        result = 2;
+      }
+    } catch (RuntimeException e) {
+      System.out.println(e.toString());
+    }
+    return result;
+  }
+}
+
+class CustomException extends Exception {
+  CustomException(String message) {
+    super(message);
+  }
+
+  CustomException(String message, Throwable cause) {
+    super(message, cause);
+  }
+}
@@ -0,0 +1,111 @@
+class LayoutBase {
+    static int created = 0;
+    static String label = "base";
+    int id;
+    long total;
+    String name = "base";
+
+    static {
+        System.out.println("LayoutBase <clinit>");
+    }
+
+    LayoutBase(int id) {
+        this.id = id;
+        this.total = id * 1000000000L;
+        created++;
+    }
+
+    String baseName() {
+        return name;
+    }
+}
+
+class LayoutChild extends LayoutBase {
+    String name = "child";
+    double ratio;
+
+    static {
+        System.out.println("LayoutChild <clinit>");
+    }
+
+    LayoutChild(int id, double ratio) {
+        super(id);
+        this.ratio = ratio;
+    }
+
+    String names() {
+        return name + "/" + super.name + "/" + baseName();
+    }
+}
+
+class LayoutLeaf extends LayoutChild {
+    int id = -1;
+
+    LayoutLeaf(int id) {
+        super(id, id / 2.0);
+    }
+
+    String describe() {
+        return id + " " + ((LayoutBase) this).id + " " + total + " " + ratio;
+    }
+}
+
+class LayoutCell {
+    int value;
+    LayoutCell next;
+
+    LayoutCell(int value, LayoutCell next) {
+        this.value = value;
+        this.next = next;
+    }
+}
+
+interface LayoutKonst {
+    int[] ARR = { 1, 2, 3 };
+    String LABEL = "konst " + ARR.length;
+}
+
+interface LayoutSubKonst extends LayoutKonst {
+    int[] SUB = { ARR[2] * 10 };
+}
+
+class LayoutKonstBase {
+    static String KIND = "base kind";
+}
+
+class LayoutKonstImpl extends LayoutKonstBase implements LayoutSubKonst {
+}
+
+public class FieldLayout {
+    public static void main(String[] args) {
+        System.out.println(LayoutChild.label);
+        System.out.println(LayoutChild.created);
+
+        LayoutLeaf leaf = new LayoutLeaf(3);
+        System.out.println(leaf.describe());
+        System.out.println(leaf.names());
+        leaf.name = "renamed";
+        ((LayoutBase) leaf).name = "base renamed";
+        System.out.println(leaf.names());
+
+        LayoutChild child = new LayoutChild(7, 0.25);
+        System.out.println(child.id + " " + child.total + " " + child.ratio);
+        LayoutChild.created += 10;
+        System.out.println(LayoutBase.created);
+
+        LayoutCell head = null;
+        for (int i = 0; i < 20000; i++) {
+            head = new LayoutCell(i, head);
+        }
+        long sum = 0;
+        for (LayoutCell cell = head; cell != null; cell = cell.next) {
+            sum += cell.value;
+        }
+        System.out.println(sum);
+
+        System.out.println(LayoutKonstImpl.ARR[1] + " " + LayoutKonstImpl.SUB[0]);
+        System.out.println(LayoutKonstImpl.LABEL + " " + LayoutKonstImpl.KIND);
+        LayoutKonstImpl.ARR[1] = 20;
+        System.out.println(LayoutKonst.ARR[1]);
+    }
+}
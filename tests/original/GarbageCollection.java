@@ -0,0 +1,114 @@
+class GcNode {
+    int value;
+    GcNode next;
+    int[] payload;
+
+    GcNode(int value, GcNode next) {
+        this.value = value;
+        this.next = next;
+        this.payload = new int[16];
+        this.payload[0] = value;
+    }
+}
+
+class GcHolder {
+    static GcNode kept = new GcNode(-1, null);
+    static String[] names = new String[3];
+}
+
+class GcTable {
+    static int[][] rows = new int[8][];
+    static long total = 0;
+
+    // the garbage of the static initializer is larger than the heap
+    static {
+        for (int i = 0; i < 300; i++) {
+            int[] chunk = new int[100000];
+            chunk[0] = i;
+            total += chunk[0];
+            rows[i % 8] = chunk;
+        }
+    }
+}
+
+class GcLabel {
+    public String toString() {
+        long total = 0;
+        for (int i = 0; i < 300; i++) {
+            int[] chunk = new int[100000];
+            chunk[99999] = i;
+            total += chunk[99999];
+        }
+        return "label " + total;
+    }
+}
+
+public class GarbageCollection {
+    static int sumChain(GcNode node) {
+        int sum = 0;
+        while (node != null) {
+            sum += node.payload[0];
+            node = node.next;
+        }
+        return sum;
+    }
+
+    public static void main(String[] args) {
+        // short-lived arrays much larger than the default heap in total
+        long total = 0;
+        for (int i = 0; i < 20000; i++) {
+            int[] chunk = new int[1000];
+            chunk[999] = i;
+            total += chunk[999];
+        }
+        System.out.println("total: " + total);
+
+        // a chain which is alive while the garbage is collected
+        GcNode head = null;
+        for (int i = 0; i < 100; i++) {
+            head = new GcNode(i, head);
+            for (int j = 0; j < 100; j++) {
+                new GcNode(j, null);
+            }
+        }
+        System.out.println("chain: " + sumChain(head));
+
+        // objects reachable from the static fields
+        GcHolder.kept.next = new GcNode(42, null);
+        GcHolder.names[1] = "name" + 1;
+        for (int i = 0; i < 3000; i++) {
+            long[] garbage = new long[1000];
+            garbage[0] = i;
+        }
+        System.out.println("kept: " + GcHolder.kept.next.value);
+        System.out.println("names: " + GcHolder.names[0] + " " + GcHolder.names[1]);
+
+        // the elements of the object arrays are null until they are stored
+        GcNode[] nodes = new GcNode[4];
+        nodes[2] = new GcNode(7, null);
+        for (int i = 0; i < nodes.length; i++) {
+            System.out.println("nodes[" + i + "]: " + (nodes[i] == null ? "null" : "" + nodes[i].value));
+        }
+        GcNode[][] grid = new GcNode[2][3];
+        System.out.println("grid: " + (grid[1][2] == null));
+
+        try {
+            long[] huge = new long[Integer.MAX_VALUE - 8];
+            System.out.println("allocated: " + huge.length);
+        } catch (OutOfMemoryError e) {
+            System.out.println("caught: " + e.getMessage());
+        }
+        System.out.println("chain after OutOfMemoryError: " + sumChain(head));
+
+        System.out.println("table: " + GcTable.total + " " + GcTable.rows[7][0]);
+        System.out.println("toString: " + new GcLabel());
+
+        // the elements are charged the bytes of the primitive types
+        int[] large = new int[10000000];
+        large[9999999] = 7;
+        System.out.println("large: " + large[9999999]);
+        large = null;
+        byte[][] bytes = new byte[4][10000000];
+        System.out.println("bytes: " + bytes[3].length);
+    }
+}
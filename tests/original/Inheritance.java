@@ -0,0 +1,90 @@
+public class Inheritance {
+  public static void main(String[] args) {
+    Animal animal = new Animal("animal");
+    Animal dog = new Dog("dog");
+    Animal puppy = new Puppy("puppy");
+    animal.speak();
+    dog.speak();
+    puppy.speak();
+    System.out.println(puppy.getLegs());
+
+    Puppy other = new Puppy("other");
+    System.out.println(other.parentWord());
+    System.out.println(other.getTricks());
+    System.out.println(other.reveal());
+    System.out.println(other.secret());
+    System.out.println(other.hidden);
+    System.out.println(((Dog) other).hidden);
+  }
+}
+
+class Animal {
+  String name;
+  int legs = 4;
+
+  Animal(String name) {
+    this.name = name;
+  }
+
+  String word() {
+    return "...";
+  }
+
+  void speak() {
+    System.out.println(name);
+    System.out.println(word());
+  }
+
+  int getLegs() {
+    return legs;
+  }
+
+  private String secret() {
+    return "secret";
+  }
+
+  String reveal() {
+    return secret();
+  }
+}
+
+class Dog extends Animal {
+  int tricks;
+  int hidden = 10;
+
+  Dog(String name) {
+    super(name);
+    tricks = 1;
+  }
+
+  String word() {
+    return "woof";
+  }
+
+  int getTricks() {
+    return tricks;
+  }
+}
+
+class Puppy extends Dog {
+  int hidden = 20;
+
+  Puppy(String name) {
+    super(name);
+    legs = 3;
+    tricks = 2;
+  }
+
+  String word() {
+    return "yip";
+  }
+
+  String parentWord() {
+    return super.word();
+  }
+
+  // doesn't override the private method of Animal
+  String secret() {
+    return "puppy secret";
+  }
+}
@@ -0,0 +1,88 @@
+interface Area {
+    int area();
+}
+
+class AreaSquare implements Area {
+    int side;
+
+    AreaSquare(int side) {
+        this.side = side;
+    }
+
+    public int area() {
+        return side * side;
+    }
+}
+
+class AreaRect implements Area {
+    int width;
+    int height;
+
+    AreaRect(int width, int height) {
+        this.width = width;
+        this.height = height;
+    }
+
+    public int area() {
+        return width * height;
+    }
+}
+
+class Stepper {
+    int count;
+
+    int step() {
+        count += 1;
+        return count;
+    }
+}
+
+class TenStepper extends Stepper {
+    int step() {
+        count += 10;
+        return count;
+    }
+}
+
+class SubTenStepper extends TenStepper {
+}
+
+public class InlineCache {
+    static int total = 0;
+
+    static int sumAreas(Area[] shapes) {
+        int sum = 0;
+        for (int i = 0; i < shapes.length; i++) {
+            sum += shapes[i].area();
+        }
+        return sum;
+    }
+
+    public static void main(String[] args) {
+        Area[] shapes = new Area[6];
+        for (int i = 0; i < shapes.length; i++) {
+            if (i % 2 == 0) {
+                shapes[i] = new AreaSquare(i);
+            } else {
+                shapes[i] = new AreaRect(i, 2);
+            }
+        }
+        System.out.println(sumAreas(shapes));
+        System.out.println(sumAreas(shapes));
+
+        Stepper[] bases = { new Stepper(), new TenStepper(), new SubTenStepper(), new Stepper() };
+        for (int round = 0; round < 3; round++) {
+            for (int i = 0; i < bases.length; i++) {
+                total += bases[i].step();
+            }
+        }
+        System.out.println(total);
+        for (int i = 0; i < bases.length; i++) {
+            System.out.println(bases[i].count);
+        }
+
+        TenStepper derived = new SubTenStepper();
+        derived.count = 5;
+        System.out.println(derived.step());
+    }
+}
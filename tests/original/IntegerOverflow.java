@@ -0,0 +1,45 @@
+class IntegerOverflow {
+    public static void main(String[] args) {
+        int max = Integer.MAX_VALUE;
+        int min = Integer.MIN_VALUE;
+        long longMax = Long.MAX_VALUE;
+        System.out.println(max + 1);
+        System.out.println(min - 1);
+        System.out.println(max * 2);
+        System.out.println(min / -1);
+        System.out.println(min % -1);
+        System.out.println(longMax + 1L);
+        System.out.println(longMax * 3L);
+        System.out.println((longMax + 1L) / -1L);
+
+        int hash = 7;
+        for (int i = 0; i < 10; i++) {
+            hash = hash * 31 + 1000003;
+        }
+        System.out.println(hash);
+        hash += 127;
+        hash -= 128;
+        System.out.println(hash);
+        short value = -300;
+        System.out.println(value);
+        System.out.println(-7 / 2);
+        System.out.println(-7 % 3);
+
+        int zero = hash - hash;
+        try {
+            System.out.println(max / zero);
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            System.out.println(max % zero);
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            System.out.println(longMax / zero);
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+    }
+}
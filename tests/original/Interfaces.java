@@ -0,0 +1,135 @@
+public class Interfaces {
+  public static void main(String[] args) {
+    Shape square = new Square(3);
+    Shape circle = new Circle(2);
+    System.out.println(square.area());
+    System.out.println(circle.area());
+    System.out.println(square.describe());
+    System.out.println(circle.describe());
+    System.out.println(square.kind());
+    System.out.println(Shape.unit());
+
+    Money small = new Money(10);
+    Money large = new Money(25);
+    System.out.println(small.compareTo(large));
+    Comparable<Money> comparable = large;
+    System.out.println(comparable.compareTo(small));
+    System.out.println(order(small, large));
+
+    Strategy strategy = new Doubler();
+    System.out.println(strategy.apply(21));
+
+    // the default method of the subinterface is maximally specific
+    System.out.println(new DiamondBoth().greet());
+    System.out.println(new DiamondSide().greet());
+    DiamondTop top = new DiamondBoth();
+    System.out.println(top.greet());
+  }
+
+  static <T extends Comparable<T>> int order(T left, T right) {
+    if (left.compareTo(right) >= 0) {
+      return 1;
+    }
+    return -1;
+  }
+}
+
+interface Named {
+  default String kind() {
+    return "shape";
+  }
+}
+
+interface Shape extends Named {
+  int area();
+
+  String name();
+
+  default String describe() {
+    return helper();
+  }
+
+  private String helper() {
+    return name();
+  }
+
+  static String unit() {
+    return "cm2";
+  }
+}
+
+class Square implements Shape {
+  int side;
+
+  Square(int side) {
+    this.side = side;
+  }
+
+  public int area() {
+    return side * side;
+  }
+
+  public String name() {
+    return "square";
+  }
+}
+
+class Circle implements Shape {
+  int radius;
+
+  Circle(int radius) {
+    this.radius = radius;
+  }
+
+  public int area() {
+    return 3 * radius * radius;
+  }
+
+  public String name() {
+    return "circle";
+  }
+
+  public String describe() {
+    return "round";
+  }
+}
+
+class Money implements Comparable<Money> {
+  int amount;
+
+  Money(int amount) {
+    this.amount = amount;
+  }
+
+  public int compareTo(Money other) {
+    return amount - other.amount;
+  }
+}
+
+interface Strategy {
+  int apply(int value);
+}
+
+class Doubler implements Strategy {
+  public int apply(int value) {
+    return value * 2;
+  }
+}
+
+interface DiamondTop {
+  default String greet() {
+    return "top";
+  }
+}
+
+interface DiamondMiddle extends DiamondTop {
+  default String greet() {
+    return "middle";
+  }
+}
+
+interface DiamondPlain extends DiamondTop {}
+
+class DiamondBoth implements DiamondTop, DiamondMiddle {}
+
+class DiamondSide implements DiamondPlain, DiamondMiddle {}
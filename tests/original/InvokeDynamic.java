@@ -0,0 +1,89 @@
+interface IntOperator {
+  int apply(int left, int right);
+}
+
+interface Greeter {
+  String greet(String name);
+}
+
+interface Describer {
+  String describe(Point point);
+}
+
+interface PointFactory {
+  Point create(int x, int y);
+}
+
+class Point {
+  int x;
+  int y;
+
+  Point(int x, int y) {
+    this.x = x;
+    this.y = y;
+  }
+
+  public String toString() {
+    return "(" + x + ", " + y + ")";
+  }
+}
+
+class Scaler {
+  int factor;
+
+  Scaler(int factor) {
+    this.factor = factor;
+  }
+
+  IntOperator scaled() {
+    return (left, right) -> (left + right) * factor;
+  }
+}
+
+class InvokeDynamic {
+  public static void main(String[] args) {
+    float f = 1.5f;
+    int count = 3;
+    char c = 'c';
+    boolean flag = true;
+    String name = "r-jvm";
+    String nothing = null;
+    System.out.println("count: " + count);
+    System.out.println(name + " " + c + " " + flag + " " + f + " " + nothing);
+
+    Point point = new Point(1, 2);
+    System.out.println("point: " + point);
+
+    IntOperator add = (left, right) -> left + right;
+    System.out.println(add.apply(2, 3));
+
+    IntOperator addCount = (left, right) -> left + right + count;
+    System.out.println(addCount.apply(2, 3));
+
+    Greeter greeter = target -> "hello " + target;
+    System.out.println(greeter.greet(name));
+
+    Runnable runnable = () -> System.out.println("run");
+    runnable.run();
+
+    IntOperator multiply = InvokeDynamic::multiply;
+    System.out.println(multiply.apply(4, 5));
+
+    PointFactory factory = Point::new;
+    System.out.println("created: " + factory.create(3, 4));
+
+    Describer describer = Point::toString;
+    System.out.println(describer.describe(point));
+
+    System.out.println(new Scaler(10).scaled().apply(1, 2));
+
+    char high = (char) 0xD800;
+    String lone = "x" + high;
+    System.out.println("lone surrogate: " + lone.equals("x" + high));
+    System.out.println("accent: " + ("caf" + (char) 0xE9).equals("caf\u00e9"));
+  }
+
+  static int multiply(int left, int right) {
+    return left * right;
+  }
+}
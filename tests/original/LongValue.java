@@ -0,0 +1,49 @@
+public class LongValue {
+  static long balance = -1234567890123L;
+  static double rate = 2.5;
+  static double penalty = -0.125;
+  long amount;
+  double ratio;
+
+  public static void main(String[] args) {
+    long a = 10000000000L;
+    long b = -3L;
+    System.out.println(a - b);
+    System.out.println(b - a);
+    System.out.println(a * b);
+    System.out.println(a / b);
+    System.out.println(a % b);
+    System.out.println(balance + a);
+    balance = balance * 2;
+    System.out.println(balance);
+    System.out.println(rate);
+    System.out.println(penalty);
+    System.out.println("rate: " + rate);
+
+    LongValue value = new LongValue();
+    value.amount = b - 9007199254740993L;
+    System.out.println(value.amount);
+    System.out.println(value.ratio);
+
+    long[] history = {a, b, Long.MIN_VALUE};
+    System.out.println(history[2]);
+    System.out.println(history[0] + history[1]);
+    if (b < a) {
+      System.out.println("less");
+    }
+    System.out.println("total: " + (a + b));
+
+    double large = 1e20;
+    double small = 1e-5;
+    System.out.println(large);
+    System.out.println(small);
+    System.out.println(123456789.0);
+    System.out.println(9999999.0);
+    System.out.println(0.001);
+    System.out.println(-2.5e-7);
+    System.out.println("doubles: " + large + " " + small + " " + (a * 1.5));
+    float ratio = 1.5e8f;
+    System.out.println(ratio);
+    System.out.println("float: " + (ratio / 1e15f));
+  }
+}
@@ -0,0 +1,65 @@
+class Ping {
+    static int calls = 0;
+
+    static int ping(int n) {
+        calls++;
+        if (n <= 0) {
+            return 0;
+        }
+        return Pong.pong(n - 1) + 1;
+    }
+}
+
+class Pong {
+    static int calls = 0;
+
+    static int pong(int n) {
+        calls++;
+        if (n <= 0) {
+            return 0;
+        }
+        return Ping.ping(n - 1) + 10;
+    }
+}
+
+class Counter {
+    static int total = 100;
+    int value;
+
+    Counter(int value) {
+        this.value = value;
+        total += value;
+    }
+
+    int sumDown(Counter other) {
+        if (value <= 0) {
+            return total;
+        }
+        Counter next = new Counter(value - 1);
+        return next.sumDown(this);
+    }
+}
+
+public class MethodArea {
+    static int fibCalls = 0;
+
+    static int fib(int n) {
+        fibCalls++;
+        if (n < 2) {
+            return n;
+        }
+        return fib(n - 1) + fib(n - 2);
+    }
+
+    public static void main(String[] args) {
+        System.out.println(fib(15));
+        System.out.println(fibCalls);
+        System.out.println(Ping.ping(7));
+        System.out.println(Ping.calls);
+        System.out.println(Pong.calls);
+        System.out.println(Ping.ping(4));
+        System.out.println(Ping.calls + Pong.calls);
+        System.out.println(new Counter(5).sumDown(null));
+        System.out.println(Counter.total);
+    }
+}
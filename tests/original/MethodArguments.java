@@ -0,0 +1,85 @@
+public class MethodArguments {
+    int base;
+
+    MethodArguments(int base) {
+        this.base = base;
+    }
+
+    static int sumRange(int from, int to) {
+        int total = 0;
+        for (int i = from; i <= to; i++) {
+            int square = i * i;
+            total += square;
+        }
+        return total;
+    }
+
+    static void mix(int a, long b, int c, double d, float e) {
+        long scaled = b * a;
+        double product = d * c;
+        float half = e / 2;
+        long result = scaled + (long) product + (long) half;
+        System.out.println(result);
+    }
+
+    static double average(double first, double second, double third) {
+        double total = first + second + third;
+        int count = 3;
+        return total / count;
+    }
+
+    static void scale(float value, int times) {
+        float result = value;
+        float step = 0.5f;
+        for (int i = 0; i < times; i++) {
+            result += step;
+        }
+        System.out.println(result);
+    }
+
+    int addBase(long offset, int extra) {
+        int local = extra * 2;
+        long sum = base + offset + local;
+        return (int) sum;
+    }
+
+    String describe(String prefix, int count, long total) {
+        String label = prefix + ":";
+        int doubled = count * 2;
+        long tripled = total * 3;
+        return label + doubled + "/" + tripled;
+    }
+
+    static void printLocals() {
+        int a = 1;
+        int b = 2;
+        int c = 3;
+        int d = 4;
+        int e = 5;
+        long f = 6L;
+        float g = 7.5f;
+        double h = 8.25;
+        String i = "nine";
+        System.out.println(a + b + c + d + e);
+        System.out.println(f);
+        System.out.println(g);
+        System.out.println(h);
+        System.out.println(i);
+    }
+
+    public static void main(String[] args) {
+        System.out.println(sumRange(1, 10));
+        mix(3, 1000000000000L, 4, 2.5, 9.0f);
+        System.out.println(average(1.0, 2.0, 4.5));
+        scale(1.25f, 3);
+
+        MethodArguments arguments = new MethodArguments(100);
+        System.out.println(arguments.addBase(20L, 5));
+        System.out.println(arguments.describe("items", 21, 5000000000L));
+        printLocals();
+
+        int untouched = 42;
+        System.out.println(sumRange(3, 4));
+        System.out.println(untouched);
+    }
+}
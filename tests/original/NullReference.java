@@ -0,0 +1,90 @@
+class NullReference {
+    int value;
+    NullReference next;
+
+    int getValue() {
+        return value;
+    }
+
+    public static void main(String[] args) {
+        NullReference first = new NullReference();
+        NullReference second = new NullReference();
+        NullReference third = first;
+        if (first == third) {
+            System.out.println("same");
+        }
+        if (first != second) {
+            System.out.println("different");
+        }
+        if (first.next == null) {
+            System.out.println("next is null");
+        }
+        first.next = second;
+        if (first.next != null) {
+            System.out.println("next is not null");
+        }
+        if (first.next == second) {
+            System.out.println("next is second");
+        }
+        String text = null;
+        System.out.println(text == null ? "text is null" : "text is not null");
+        System.out.println(text);
+
+        third = null;
+        int[] ints = null;
+        NullReference[] refs = null;
+        Runnable runnable = null;
+        RuntimeException exception = null;
+        try {
+            third.value = 1;
+        } catch (NullPointerException e) {
+            System.out.println("putfield");
+        }
+        try {
+            System.out.println(third.value);
+        } catch (NullPointerException e) {
+            System.out.println("getfield");
+        }
+        try {
+            third.getValue();
+        } catch (NullPointerException e) {
+            System.out.println("invokevirtual");
+        }
+        try {
+            runnable.run();
+        } catch (NullPointerException e) {
+            System.out.println("invokeinterface");
+        }
+        try {
+            System.out.println(ints.length);
+        } catch (NullPointerException e) {
+            System.out.println("arraylength");
+        }
+        try {
+            ints[0] = 1;
+        } catch (NullPointerException e) {
+            System.out.println("iastore");
+        }
+        try {
+            System.out.println(ints[0]);
+        } catch (NullPointerException e) {
+            System.out.println("iaload");
+        }
+        try {
+            refs[0] = first;
+        } catch (NullPointerException e) {
+            System.out.println("aastore");
+        }
+        try {
+            third = refs[0];
+        } catch (NullPointerException e) {
+            System.out.println("aaload");
+        }
+        try {
+            throw exception;
+        } catch (NullPointerException e) {
+            System.out.println("athrow");
+        }
+        System.out.println(first.next.getValue());
+    }
+}
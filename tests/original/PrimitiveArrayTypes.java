@@ -0,0 +1,79 @@
+class PrimitiveArrayTypes {
+    public static void main(String[] args) {
+        byte[] bytes = new byte[2];
+        char[] chars = new char[3];
+        short[] shorts = new short[2];
+        float[] floats = new float[2];
+        double[] doubles = new double[2];
+        boolean[] booleans = new boolean[2];
+
+        int value = 300;
+        bytes[0] = (byte) value;
+        bytes[1] = -5;
+        System.out.println(bytes[0] + bytes[1]);
+        chars[0] = 'a';
+        chars[1] = (char) (chars[0] + 1);
+        System.out.println(chars[1] - chars[2]);
+        shorts[0] = (short) (value * 200);
+        System.out.println(shorts[0]);
+        System.out.println(shorts[1]);
+        floats[1] = 2.5f;
+        System.out.println(floats[0] + floats[1]);
+        doubles[0] = floats[1] * 2;
+        System.out.println(doubles[0]);
+        booleans[1] = true;
+        if (booleans[1] && !booleans[0]) {
+            System.out.println("boolean");
+        }
+        System.out.println(bytes.length + chars.length + doubles.length);
+
+        char[][] grid = new char[2][3];
+        grid[1][2] = 'z';
+        System.out.println(grid[1][2] + grid[0][0]);
+        System.out.println(grid.length * grid[1].length);
+        float[][] matrix = new float[3][2];
+        matrix[2][1] = 1.5f;
+        System.out.println(matrix[2][1] + matrix[0][0]);
+        boolean[][] flags = new boolean[2][2];
+        if (!flags[1][1]) {
+            System.out.println("false");
+        }
+
+        try {
+            chars[value - 297] = 'b';
+        } catch (ArrayIndexOutOfBoundsException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            value = chars[value - 301];
+        } catch (ArrayIndexOutOfBoundsException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            doubles = new double[value - 302];
+        } catch (NegativeArraySizeException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            grid = new char[2][value - 301];
+        } catch (NegativeArraySizeException e) {
+            System.out.println(e.getMessage());
+        }
+
+        int[][][] cube = new int[2][3][];
+        System.out.println(cube[1].length + " " + (cube[1][2] == null));
+        cube[1][2] = new int[4];
+        cube[1][2][3] = 7;
+        System.out.println(cube[1][2].length + " " + cube[1][2][3]);
+
+        long[][] rows = new long[3][2];
+        rows[1] = null;
+        System.out.println("null row " + (rows[1] == null));
+        rows[1] = new long[5];
+        System.out.println(rows[1].length);
+        String[][][] names = new String[2][2][];
+        names[0][1] = new String[] { "x" };
+        names[0][0] = null;
+        System.out.println(names[0][1][0] + " " + (names[1][0] == null));
+    }
+}
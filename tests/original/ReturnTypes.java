@@ -0,0 +1,70 @@
+public class ReturnTypes {
+    long total;
+
+    ReturnTypes(long total) {
+        this.total = total;
+    }
+
+    static long square(long value) {
+        long result = value * value;
+        return result;
+    }
+
+    static float half(float value) {
+        return value / 2;
+    }
+
+    static double third(double value) {
+        return value / 3;
+    }
+
+    static int negate(int value) {
+        return -value;
+    }
+
+    static String greet(String name) {
+        return "hello " + name;
+    }
+
+    long addTotal(long value) {
+        total += value;
+        return total;
+    }
+
+    float ratio(int numerator, int denominator) {
+        float result = (float) numerator / denominator;
+        return result;
+    }
+
+    static long nested(long value) {
+        return square(value) + square(value + 1);
+    }
+
+    public static void main(String[] args) {
+        System.out.println(square(3000000L));
+        System.out.println(half(5.0f));
+        System.out.println(third(10.0));
+        System.out.println(negate(7));
+        System.out.println(greet("crate"));
+
+        ReturnTypes returnTypes = new ReturnTypes(10000000000L);
+        System.out.println(returnTypes.addTotal(5L));
+        System.out.println(returnTypes.ratio(1, 4));
+        System.out.println(nested(2L));
+
+        long sum = square(2L) + square(3L);
+        float product = half(3.0f) * half(4.0f);
+        System.out.println(sum);
+        System.out.println(product);
+
+        System.out.println(Math.abs(-5));
+        System.out.println(Math.abs(-9000000000L));
+        System.out.println(Math.abs(-2.5f));
+        System.out.println(Math.abs(-1.25));
+        System.out.println(Math.max(3, 8));
+        System.out.println(Math.min(-4000000000L, 12L));
+        System.out.println(Math.max(1.5f, -2.5f));
+        System.out.println(Math.min(0.0, -0.0));
+        System.out.println(Math.max(Math.abs(-3L), 2L) + square(2L));
+    }
+}
@@ -0,0 +1,12 @@
+public class Settings {
+    public static final int MAX_SIZE = 1024;
+    public static final int NEGATIVE = -100000;
+    public static final long TIMEOUT = 30000000000L;
+    public static final float RATIO = 0.75f;
+    public static final double PI = 3.14159;
+    public static final String NAME = "crate";
+    public static final boolean ENABLED = true;
+    public static final char SEPARATOR = ':';
+    public static final byte LEVEL = -3;
+    public static final short PORT = 8080;
+}
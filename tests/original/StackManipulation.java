@@ -0,0 +1,38 @@
+class StackManipulation {
+    int value;
+    long longValue;
+    static long counter;
+
+    static double half(double d) {
+        return d / 2;
+    }
+
+    public static void main(String[] args) {
+        StackManipulation s = new StackManipulation();
+        long l = 0L;
+        int[] ints = new int[3];
+        long[] longs = new long[2];
+
+        int a = s.value = 7;
+        System.out.println(a + s.value);
+        a = ints[1] = 9;
+        System.out.println(a + ints[1]);
+
+        l = s.longValue = 5L;
+        System.out.println(l + s.longValue);
+        l = longs[1] = 11L;
+        System.out.println(l + longs[1]);
+
+        longs[0] += 3L;
+        longs[0] += 4L;
+        System.out.println(longs[0]);
+        s.longValue++;
+        System.out.println(s.longValue);
+        l = counter++;
+        l = ++counter;
+        System.out.println(l + counter);
+
+        half(3.0);
+        System.out.println(half(5.0));
+    }
+}
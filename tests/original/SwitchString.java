@@ -0,0 +1,55 @@
+enum Command {
+    START, STOP, PAUSE, RESUME
+}
+
+public class SwitchString {
+    static String dispatch(String command) {
+        switch (command) {
+            case "start":
+                return "starting";
+            case "stop":
+                return "stopping";
+            case "Aa":
+                return "collision Aa";
+            case "BB":
+                return "collision BB";
+            default:
+                return "unknown " + command;
+        }
+    }
+
+    static int priority(Command command) {
+        switch (command) {
+            case START:
+                return 10;
+            case STOP:
+                return 20;
+            case RESUME:
+                return 30;
+            default:
+                return -1;
+        }
+    }
+
+    public static void main(String[] args) {
+        System.out.println(dispatch("start"));
+        System.out.println(dispatch("stop"));
+        System.out.println(dispatch("Aa"));
+        System.out.println(dispatch("BB"));
+        System.out.println(dispatch("jump"));
+
+        System.out.println("start".hashCode());
+        System.out.println("Aa".hashCode());
+        System.out.println("BB".hashCode());
+        System.out.println("".hashCode());
+        System.out.println("a much longer command name".hashCode());
+        System.out.println("equals " + "start".equals("start"));
+        System.out.println("equals " + "start".equals("stop"));
+
+        System.out.println(priority(Command.START));
+        System.out.println(priority(Command.STOP));
+        System.out.println(priority(Command.PAUSE));
+        System.out.println(priority(Command.RESUME));
+        System.out.println(Command.PAUSE.ordinal());
+    }
+}
@@ -0,0 +1,102 @@
+public class SwitchTable {
+    static String state(int code) {
+        switch (code) {
+            case -2:
+                return "failed";
+            case -1:
+                return "cancelled";
+            case 0:
+                return "idle";
+            case 1:
+                return "running";
+            case 2:
+                return "done";
+            default:
+                return "unknown";
+        }
+    }
+
+    static String sparse(int code) {
+        switch (code) {
+            case -100000:
+                return "very negative";
+            case -7:
+                return "negative";
+            case 42:
+                return "answer";
+            case 2000000000:
+                return "large";
+            default:
+                return "other";
+        }
+    }
+
+    static int fallThrough(int value) {
+        int result = 0;
+        switch (value) {
+            case 1:
+                result += 1;
+            case 2:
+                result += 10;
+            case 3:
+                result += 100;
+                break;
+            case 4:
+                result = -1;
+                break;
+        }
+        return result;
+    }
+
+    static void manyLocals() {
+        long l0 = 0L, l1 = 1L, l2 = 2L, l3 = 3L, l4 = 4L, l5 = 5L, l6 = 6L, l7 = 7L,
+            l8 = 8L, l9 = 9L, l10 = 10L, l11 = 11L, l12 = 12L, l13 = 13L, l14 = 14L, l15 = 15L,
+            l16 = 16L, l17 = 17L, l18 = 18L, l19 = 19L, l20 = 20L, l21 = 21L, l22 = 22L, l23 = 23L,
+            l24 = 24L, l25 = 25L, l26 = 26L, l27 = 27L, l28 = 28L, l29 = 29L, l30 = 30L, l31 = 31L,
+            l32 = 32L, l33 = 33L, l34 = 34L, l35 = 35L, l36 = 36L, l37 = 37L, l38 = 38L, l39 = 39L,
+            l40 = 40L, l41 = 41L, l42 = 42L, l43 = 43L, l44 = 44L, l45 = 45L, l46 = 46L, l47 = 47L,
+            l48 = 48L, l49 = 49L, l50 = 50L, l51 = 51L, l52 = 52L, l53 = 53L, l54 = 54L, l55 = 55L,
+            l56 = 56L, l57 = 57L, l58 = 58L, l59 = 59L, l60 = 60L, l61 = 61L, l62 = 62L, l63 = 63L,
+            l64 = 64L, l65 = 65L, l66 = 66L, l67 = 67L, l68 = 68L, l69 = 69L, l70 = 70L, l71 = 71L,
+            l72 = 72L, l73 = 73L, l74 = 74L, l75 = 75L, l76 = 76L, l77 = 77L, l78 = 78L, l79 = 79L,
+            l80 = 80L, l81 = 81L, l82 = 82L, l83 = 83L, l84 = 84L, l85 = 85L, l86 = 86L, l87 = 87L,
+            l88 = 88L, l89 = 89L, l90 = 90L, l91 = 91L, l92 = 92L, l93 = 93L, l94 = 94L, l95 = 95L,
+            l96 = 96L, l97 = 97L, l98 = 98L, l99 = 99L, l100 = 100L, l101 = 101L, l102 = 102L, l103 = 103L,
+            l104 = 104L, l105 = 105L, l106 = 106L, l107 = 107L, l108 = 108L, l109 = 109L, l110 = 110L, l111 = 111L,
+            l112 = 112L, l113 = 113L, l114 = 114L, l115 = 115L, l116 = 116L, l117 = 117L, l118 = 118L, l119 = 119L,
+            l120 = 120L, l121 = 121L, l122 = 122L, l123 = 123L, l124 = 124L, l125 = 125L, l126 = 126L, l127 = 127L,
+            l128 = 128L, l129 = 129L;
+        int counter = 5;
+        counter += 1000;
+        counter -= 300;
+        long total = l0 + l64 + l127 + l129;
+        float ratio = 1.5f;
+        double scale = 2.25;
+        String label = "wide";
+        System.out.println(counter);
+        System.out.println(total);
+        System.out.println(ratio);
+        System.out.println(scale);
+        System.out.println(label);
+    }
+
+    public static void main(String[] args) {
+        for (int i = -3; i <= 3; i++) {
+            System.out.println(i + " " + state(i));
+        }
+        System.out.println(sparse(-100000));
+        System.out.println(sparse(-7));
+        System.out.println(sparse(42));
+        System.out.println(sparse(2000000000));
+        System.out.println(sparse(7));
+        for (int i = 0; i <= 5; i++) {
+            System.out.println(fallThrough(i));
+        }
+
+        int big = 10;
+        big += 5000;
+        big -= 129;
+        System.out.println(big);
+        manyLocals();
+    }
+}
@@ -0,0 +1,128 @@
+interface Drivable {
+}
+
+class Vehicle {
+}
+
+class Car extends Vehicle implements Drivable {
+    String name() {
+        return "dog";
+    }
+}
+
+class Bike extends Vehicle {
+}
+
+class TypeCheck {
+    static void print(boolean value) {
+        if (value) {
+            System.out.println("true");
+        } else {
+            System.out.println("false");
+        }
+    }
+
+    public static void main(String[] args) {
+        Object dog = new Car();
+        Object cat = new Bike();
+        Object text = "text";
+        Object nothing = null;
+        print(dog instanceof Vehicle);
+        print(dog instanceof Drivable);
+        print(dog instanceof Object);
+        print(cat instanceof Drivable);
+        print(cat instanceof Car);
+        print(text instanceof String);
+        print(text instanceof Vehicle);
+        print(text instanceof Comparable);
+        print(text instanceof CharSequence);
+        print(text instanceof java.io.Serializable);
+        print(text instanceof Runnable);
+        print(nothing instanceof Object);
+
+        Object strings = new String[2];
+        Object ints = new int[3];
+        Object dogs = new Car[2][2];
+        Vehicle[] animals = new Car[1];
+        print(strings instanceof Object[]);
+        print(strings instanceof String[]);
+        print(strings instanceof Integer[]);
+        print(ints instanceof int[]);
+        print(ints instanceof Object[]);
+        print(ints instanceof long[]);
+        print(ints instanceof Cloneable);
+        print(dogs instanceof Vehicle[][]);
+        print(dogs instanceof Drivable[][]);
+        print(dogs instanceof Object[]);
+        print(dogs instanceof Bike[][]);
+        print(animals instanceof Car[]);
+
+        Object exception = new ArithmeticException();
+        print(exception instanceof RuntimeException);
+        print(exception instanceof Throwable);
+        print(exception instanceof Error);
+
+        Vehicle animal = (Vehicle) dog;
+        Drivable pet = (Drivable) dog;
+        Car nullDog = (Car) nothing;
+        Object[] objects = (Object[]) dogs;
+        Comparable comparable = (Comparable) text;
+        CharSequence sequence = (CharSequence) text;
+        print(exception instanceof java.io.Serializable);
+        if (animal instanceof Car d) {
+            System.out.println(d.name());
+        }
+        try {
+            Car castDog = (Car) cat;
+            System.out.println("not thrown");
+        } catch (ClassCastException e) {
+            System.out.println("ClassCastException");
+        }
+        try {
+            String castText = (String) dog;
+            System.out.println("not thrown");
+        } catch (ClassCastException e) {
+            System.out.println("ClassCastException");
+        }
+        try {
+            long[] longs = (long[]) ints;
+            System.out.println("not thrown");
+        } catch (ClassCastException e) {
+            System.out.println("ClassCastException");
+        }
+
+        // the stores into the covariant arrays are checked with the component type
+        Object[] covariant = new String[2];
+        covariant[0] = "stored";
+        covariant[1] = null;
+        try {
+            covariant[1] = new Object();
+            System.out.println("not thrown");
+        } catch (ArrayStoreException e) {
+            System.out.println("ArrayStoreException " + e.getMessage());
+        }
+        System.out.println(covariant[0] + " " + covariant[1]);
+        Object[] flat = new int[2][2];
+        flat[1] = new int[3];
+        try {
+            flat[0] = "x";
+            System.out.println("not thrown");
+        } catch (ArrayStoreException e) {
+            System.out.println("ArrayStoreException " + e.getMessage());
+        }
+        try {
+            flat[0] = new long[1];
+            System.out.println("not thrown");
+        } catch (ArrayStoreException e) {
+            System.out.println("ArrayStoreException " + e.getMessage());
+        }
+        Object[] vehicles = new Car[1];
+        vehicles[0] = new Car();
+        try {
+            vehicles[0] = new Bike();
+            System.out.println("not thrown");
+        } catch (ArrayStoreException e) {
+            System.out.println("ArrayStoreException " + e.getMessage());
+        }
+    }
+}
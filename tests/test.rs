@@ -2,7 +2,9 @@ use r_jvm;
 
 fn test_helper(file_name: String) {
     println!("** {} **", &file_name);
-    r_jvm::execute(file_name, 0);
+    if !r_jvm::execute(file_name, 0) {
+        println!("uncaught exception");
+    }
     println!("");
 }
 
@@ -24,4 +26,29 @@ fn main() {
     test_helper(String::from("tests/class/LongCulculate"));
     test_helper(String::from("tests/class/FloatCulculate"));
     test_helper(String::from("tests/class/DconstN"));
+    test_helper(String::from("tests/class/ExceptionHandling"));
+    test_helper(String::from("tests/class/Inheritance"));
+    test_helper(String::from("tests/class/Interfaces"));
+    test_helper(String::from("tests/class/InvokeDynamic"));
+    test_helper(String::from("tests/class/LongValue"));
+    test_helper(String::from("tests/class/DoubleCulculate"));
+    test_helper(String::from("tests/class/Conversion"));
+    test_helper(String::from("tests/class/Bitwise"));
+    test_helper(String::from("tests/class/StackManipulation"));
+    test_helper(String::from("tests/class/PrimitiveArrayTypes"));
+    test_helper(String::from("tests/class/NullReference"));
+    test_helper(String::from("tests/class/IntegerOverflow"));
+    test_helper(String::from("tests/class/TypeCheck"));
+    test_helper(String::from("tests/class/ClassInit"));
+    test_helper(String::from("tests/class/ConstantFields"));
+    test_helper(String::from("tests/class/MethodArguments"));
+    test_helper(String::from("tests/class/ReturnTypes"));
+    test_helper(String::from("tests/class/SwitchTable"));
+    test_helper(String::from("tests/class/SwitchString"));
+    test_helper(String::from("tests/class/EnumBasics"));
+    test_helper(String::from("tests/class/MethodArea"));
+    test_helper(String::from("tests/class/DeepRecursion"));
+    test_helper(String::from("tests/class/InlineCache"));
+    test_helper(String::from("tests/class/FieldLayout"));
+    test_helper(String::from("tests/class/GarbageCollection"));
 }